@@ -0,0 +1,152 @@
+use collections::{doubly_linked_list::DoublyLinkedList, linked_list::LinkedList};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::collections::LinkedList as StdLinkedList;
+
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+/// Pushes `n` elements at the back then pops them all from the front.
+fn queue(c: &mut Criterion) {
+    let mut group = c.benchmark_group("queue");
+    for &n in &SIZES {
+        group.bench_with_input(BenchmarkId::new("collections", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = LinkedList::new();
+                for i in 0..n {
+                    list.push_back(i);
+                }
+                while let Some(x) = list.pop_front() {
+                    black_box(x);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("doubly", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = DoublyLinkedList::new();
+                for i in 0..n {
+                    list.push_back(i);
+                }
+                while let Some(x) = list.pop_front() {
+                    black_box(x);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("std", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = StdLinkedList::new();
+                for i in 0..n {
+                    list.push_back(i);
+                }
+                while let Some(x) = list.pop_front() {
+                    black_box(x);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Interleaves pushes and pops at the front, keeping the list short.
+fn stack_mix(c: &mut Criterion) {
+    let mut group = c.benchmark_group("stack_mix");
+    for &n in &SIZES {
+        group.bench_with_input(BenchmarkId::new("collections", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = LinkedList::new();
+                for i in 0..n {
+                    list.push_front(i);
+                    list.push_front(i);
+                    black_box(list.pop_front());
+                }
+                list
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("doubly", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = DoublyLinkedList::new();
+                for i in 0..n {
+                    list.push_front(i);
+                    list.push_front(i);
+                    black_box(list.pop_front());
+                }
+                list
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("std", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = StdLinkedList::new();
+                for i in 0..n {
+                    list.push_front(i);
+                    list.push_front(i);
+                    black_box(list.pop_front());
+                }
+                list
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Looks up every tenth position of a list of `n` elements.
+fn lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lookup");
+    for &n in &SIZES {
+        let mut list = LinkedList::new();
+        let mut doubly = DoublyLinkedList::new();
+        let mut std_list = StdLinkedList::new();
+        for i in 0..n {
+            list.push_back(i);
+            doubly.push_back(i);
+            std_list.push_back(i);
+        }
+        group.bench_with_input(BenchmarkId::new("collections", n), &n, |b, &n| {
+            b.iter(|| {
+                for i in (0..n).step_by(10) {
+                    black_box(list.get(i));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("doubly", n), &n, |b, &n| {
+            b.iter(|| {
+                for i in (0..n).step_by(10) {
+                    black_box(doubly.get(i));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("std", n), &n, |b, &n| {
+            b.iter(|| {
+                for i in (0..n).step_by(10) {
+                    black_box(std_list.iter().nth(i));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Sums the elements of a list of `n` elements.
+fn iteration(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iteration");
+    for &n in &SIZES {
+        let mut list = LinkedList::new();
+        let mut doubly = DoublyLinkedList::new();
+        let mut std_list = StdLinkedList::new();
+        for i in 0..n {
+            list.push_back(i);
+            doubly.push_back(i);
+            std_list.push_back(i);
+        }
+        group.bench_with_input(BenchmarkId::new("collections", n), &n, |b, _| {
+            b.iter(|| list.iter().sum::<usize>())
+        });
+        group.bench_with_input(BenchmarkId::new("doubly", n), &n, |b, _| {
+            b.iter(|| doubly.iter().sum::<usize>())
+        });
+        group.bench_with_input(BenchmarkId::new("std", n), &n, |b, _| {
+            b.iter(|| std_list.iter().sum::<usize>())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, queue, stack_mix, lookup, iteration);
+criterion_main!(benches);
@@ -0,0 +1,128 @@
+use collections::{linked_list::LinkedList, vec_deque::VecDeque};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::collections::VecDeque as StdVecDeque;
+
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+/// Pushes `n` elements at the back then pops them all from the front,
+/// also with a `LinkedList` to compare with the per-element allocations.
+fn queue(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vec_deque_queue");
+    for &n in &SIZES {
+        group.bench_with_input(BenchmarkId::new("collections", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut deque = VecDeque::new();
+                for i in 0..n {
+                    deque.push_back(i);
+                }
+                while let Some(x) = deque.pop_front() {
+                    black_box(x);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("linked_list", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = LinkedList::new();
+                for i in 0..n {
+                    list.push_back(i);
+                }
+                while let Some(x) = list.pop_front() {
+                    black_box(x);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("std", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut deque = StdVecDeque::new();
+                for i in 0..n {
+                    deque.push_back(i);
+                }
+                while let Some(x) = deque.pop_front() {
+                    black_box(x);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Interleaves pushes and pops at the front, keeping the deque short.
+fn stack_mix(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vec_deque_stack_mix");
+    for &n in &SIZES {
+        group.bench_with_input(BenchmarkId::new("collections", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut deque = VecDeque::new();
+                for i in 0..n {
+                    deque.push_front(i);
+                    deque.push_front(i);
+                    black_box(deque.pop_front());
+                }
+                deque
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("std", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut deque = StdVecDeque::new();
+                for i in 0..n {
+                    deque.push_front(i);
+                    deque.push_front(i);
+                    black_box(deque.pop_front());
+                }
+                deque
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Indexes every tenth position of a wrapped deque of `n` elements.
+fn lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vec_deque_lookup");
+    for &n in &SIZES {
+        let mut deque = VecDeque::new();
+        let mut std_deque = StdVecDeque::new();
+        for i in 0..n {
+            deque.push_front(i);
+            std_deque.push_front(i);
+        }
+        group.bench_with_input(BenchmarkId::new("collections", n), &n, |b, &n| {
+            b.iter(|| {
+                for i in (0..n).step_by(10) {
+                    black_box(deque[i]);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("std", n), &n, |b, &n| {
+            b.iter(|| {
+                for i in (0..n).step_by(10) {
+                    black_box(std_deque[i]);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Sums the elements of a wrapped deque of `n` elements.
+fn iteration(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vec_deque_iteration");
+    for &n in &SIZES {
+        let mut deque = VecDeque::new();
+        let mut std_deque = StdVecDeque::new();
+        for i in 0..n {
+            deque.push_front(i);
+            std_deque.push_front(i);
+        }
+        group.bench_with_input(BenchmarkId::new("collections", n), &n, |b, _| {
+            b.iter(|| deque.iter().sum::<usize>())
+        });
+        group.bench_with_input(BenchmarkId::new("std", n), &n, |b, _| {
+            b.iter(|| std_deque.iter().sum::<usize>())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, queue, stack_mix, lookup, iteration);
+criterion_main!(benches);
@@ -0,0 +1,56 @@
+//! The allocator the collections are generic over.
+//!
+//! With the `allocator_api` feature, this is the unstable `Allocator`
+//! trait of `alloc`, so that any allocator can be used. Without it,
+//! a stand-in trait with the same methods is implemented by [`Global`]
+//! only, keeping the collections generic on stable.
+
+pub(crate) use self::inner::{Allocator, Global};
+
+#[cfg(feature = "allocator_api")]
+mod inner {
+    pub use alloc::alloc::{Allocator, Global};
+}
+
+#[cfg(not(feature = "allocator_api"))]
+mod inner {
+    use core::{alloc::Layout, ptr::NonNull};
+    use crate::errors::AllocError;
+
+    /// The subset of `core::alloc::Allocator` used by the collections.
+    ///
+    /// # Safety
+    ///
+    /// Same as `core::alloc::Allocator`.
+    pub unsafe trait Allocator {
+        /// Allocates a block of memory fitting `layout`.
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError>;
+
+        /// Deallocates the memory referenced by `ptr`.
+        ///
+        /// # Safety
+        ///
+        /// `ptr` must have been allocated by this allocator with `layout`.
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+    }
+
+    /// The global memory allocator.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct Global;
+
+    unsafe impl Allocator for Global {
+        #[inline]
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            debug_assert!(layout.size() != 0);
+            let ptr = unsafe { alloc::alloc::alloc(layout) };
+            NonNull::new(ptr)
+                .map(|ptr| NonNull::slice_from_raw_parts(ptr, layout.size()))
+                .ok_or(AllocError)
+        }
+
+        #[inline]
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            alloc::alloc::dealloc(ptr.as_ptr(), layout)
+        }
+    }
+}
@@ -0,0 +1,2186 @@
+//! A doubly-linked list with owned nodes.
+
+use core::{
+    alloc::Layout,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{FromIterator, FusedIterator},
+    marker::PhantomData,
+    mem,
+    ops::{Index, IndexMut},
+    ptr::{self, NonNull},
+};
+use alloc::{alloc::handle_alloc_error, boxed::Box, vec::Vec};
+use crate::{
+    allocator::{Allocator, Global},
+    errors::InsertError,
+    linked_list::LinkedList,
+    macros::impl_slice_eq,
+};
+
+/// Creates a [`DoublyLinkedList`] containing the arguments.
+///
+/// Like `vec!`, `doubly_linked_list!` accepts either a list of elements
+/// or an element and a number of repetitions, the element being cloned.
+///
+/// # Examples
+///
+/// ```
+/// use collections::doubly_linked_list;
+///
+/// let list = doubly_linked_list![1, 2, 3];
+/// assert_eq!(list, [1, 2, 3]);
+///
+/// let list = doubly_linked_list![0; 4];
+/// assert_eq!(list, [0, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! doubly_linked_list {
+    () => {
+        $crate::doubly_linked_list::DoublyLinkedList::new()
+    };
+    ($element:expr; $n:expr) => {{
+        let element = $element;
+        let mut list = $crate::doubly_linked_list::DoublyLinkedList::new();
+        for _ in 0..$n {
+            list.push_back(::core::clone::Clone::clone(&element));
+        }
+        list
+    }};
+    ($($x:expr),+ $(,)?) => {{
+        let mut list = $crate::doubly_linked_list::DoublyLinkedList::new();
+        $(list.push_back($x);)+
+        list
+    }};
+}
+
+/// A doubly-linked list with owned nodes.
+///
+/// The `DoublyLinkedList` allows pushing and popping elements at either
+/// end in constant time, iterating in both directions, and moving
+/// cursors back-and-forth. Each node links to the nodes before and
+/// after it, making it heavier than a [`LinkedList`], whose nodes only
+/// link to the next one.
+///
+/// The nodes are allocated with `A`, the global allocator by default.
+/// Other allocators can be used with the `allocator_api` feature.
+pub struct DoublyLinkedList<T, A: Allocator = Global> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    alloc: A,
+
+    /// Indicates that `DoublyLinkedList` owns some `Box<Node>`
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+struct Node<T> {
+    element: T,
+    next: Link<T>,
+    prev: Link<T>,
+}
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+/// An iterator over the elements of a `DoublyLinkedList`.
+///
+/// This `struct` is created by [`DoublyLinkedList::iter`].
+pub struct Iter<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    /// Number of elements left, ending the iteration when `head`
+    /// and `tail` meet.
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+/// A mutable iterator over the elements of a `DoublyLinkedList`.
+///
+/// This `struct` is created by [`DoublyLinkedList::iter_mut`].
+pub struct IterMut<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    /// Number of elements left, see [`Iter`].
+    len: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+/// An owning iterator over the elements of a `DoublyLinkedList`.
+///
+/// This `struct` is created by the [`into_iter`] method on
+/// [`DoublyLinkedList`] (provided by the `IntoIterator` trait).
+///
+/// [`into_iter`]: DoublyLinkedList::into_iter
+pub struct IntoIter<T, A: Allocator = Global> {
+    list: DoublyLinkedList<T, A>,
+}
+
+/// A cursor over a `DoublyLinkedList`.
+///
+/// A `Cursor` is like an iterator, except that it can freely seek
+/// back-and-forth and keeps track of its position.
+///
+/// Cursors always rest between two elements in the list, and index in a
+/// logically circular way. To accommodate this, there is a "ghost"
+/// non-element that yields `None` between the tail and head of the list.
+///
+/// This `struct` is created by [`DoublyLinkedList::cursor_front`] and
+/// [`DoublyLinkedList::cursor_back`].
+pub struct Cursor<'a, T, A: Allocator = Global> {
+    index: usize,
+    current: Link<T>,
+    list: &'a DoublyLinkedList<T, A>,
+}
+
+/// A cursor over a `DoublyLinkedList` with editing operations.
+///
+/// A `CursorMut` is like an iterator, except that it can freely seek
+/// back-and-forth, and can safely mutate the list during iteration. This is
+/// because the lifetime of its yielded references is tied to its own
+/// lifetime, instead of just the underlying list.
+///
+/// Cursors always rest between two elements in the list, and index in a
+/// logically circular way. To accommodate this, there is a "ghost"
+/// non-element that yields `None` between the tail and head of the list.
+///
+/// This `struct` is created by [`DoublyLinkedList::cursor_front_mut`] and
+/// [`DoublyLinkedList::cursor_back_mut`].
+pub struct CursorMut<'a, T, A: Allocator = Global> {
+    index: usize,
+    current: Link<T>,
+    list: &'a mut DoublyLinkedList<T, A>,
+}
+
+/// A handle to an element of a `DoublyLinkedList`.
+///
+/// It stays valid as long as its element is in the list, whatever
+/// happens to the other elements, allowing to access or remove it
+/// in constant time without searching the list.
+///
+/// This `struct` is created by [`DoublyLinkedList::push_front_handle`]
+/// and [`DoublyLinkedList::push_back_handle`].
+pub struct NodeRef<T> {
+    node: NonNull<Node<T>>,
+}
+
+// Private methods
+impl<T, A: Allocator> DoublyLinkedList<T, A> {
+    /// Allocates a node holding `element` with the list's allocator.
+    fn alloc_node(&self, element: T) -> NonNull<Node<T>> {
+        let layout = Layout::new::<Node<T>>();
+        let node = match self.alloc.allocate(layout) {
+            Ok(node) => node.cast::<Node<T>>(),
+            Err(_) => handle_alloc_error(layout),
+        };
+        unsafe {
+            node.as_ptr().write(Node {
+                element,
+                next: None,
+                prev: None,
+            });
+        }
+        node
+    }
+
+    /// Deallocates `node` and returns its element.
+    ///
+    /// # Safety
+    ///
+    /// `node` must have been allocated by the list's allocator,
+    /// and must not be linked anymore.
+    unsafe fn free_node(&self, node: NonNull<Node<T>>) -> T {
+        let element = ptr::read(&(*node.as_ptr()).element);
+        self.alloc.deallocate(node.cast(), Layout::new::<Node<T>>());
+        element
+    }
+
+    /// Returns the node at position `index`, walking from the closest end
+    /// of the list, or `None` if there is no element at that position.
+    fn node_at(&self, index: usize) -> Link<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        if index <= self.len / 2 {
+            let mut node = self.head?;
+            for _ in 0..index {
+                node = unsafe { node.as_ref() }.next?;
+            }
+            Some(node)
+        } else {
+            let mut node = self.tail?;
+            for _ in index + 1..self.len {
+                node = unsafe { node.as_ref() }.prev?;
+            }
+            Some(node)
+        }
+    }
+
+    /// Unlinks `node` from the list and returns it.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be in the list.
+    unsafe fn unlink_node(&mut self, node: NonNull<Node<T>>) -> NonNull<Node<T>> {
+        let Node { next, prev, .. } = *node.as_ptr();
+        match prev {
+            Some(prev) => (*prev.as_ptr()).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => (*next.as_ptr()).prev = prev,
+            None => self.tail = prev,
+        }
+
+        self.len -= 1;
+        node
+    }
+
+    /// Links `node` after `prev`, or at the front if `prev` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `prev` must be in the list.
+    unsafe fn link_after(&mut self, prev: Link<T>, node: NonNull<Node<T>>) {
+        let next = match prev {
+            Some(prev) => (*prev.as_ptr()).next.replace(node),
+            None => self.head.replace(node),
+        };
+        (*node.as_ptr()).next = next;
+        (*node.as_ptr()).prev = prev;
+        match next {
+            Some(next) => (*next.as_ptr()).prev = Some(node),
+            None => self.tail = Some(node),
+        }
+        self.len += 1;
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Creates an empty `DoublyLinkedList`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = DoublyLinkedList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator> DoublyLinkedList<T, A> {
+    /// Creates an empty `DoublyLinkedList` allocating its nodes with `alloc`.
+    ///
+    /// Allocators other than the global one can only be used with the
+    /// `allocator_api` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = DoublyLinkedList::new();
+    /// let mut other = DoublyLinkedList::new_in(list.allocator().clone());
+    /// other.push_back(1);
+    /// assert_eq!(other, [1]);
+    /// ```
+    #[inline]
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            alloc,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the underlying allocator.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Returns the length of the `DoublyLinkedList`.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_front(2);
+    /// list.push_back(3);
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the `DoublyLinkedList` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// assert!(list.is_empty());
+    ///
+    /// list.push_front(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Provides a double-ended iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = (0..3).collect();
+    ///
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&0));
+    /// assert_eq!(iter.next_back(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Provides a double-ended iterator with mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (0..3).collect();
+    ///
+    /// for (i, element) in list.iter_mut().rev().enumerate() {
+    ///     *element += 10 * i as u32;
+    /// }
+    /// assert_eq!(list, [20, 11, 2]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Provides a cursor at the front element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = (1..4).collect();
+    ///
+    /// let mut cursor = list.cursor_front();
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.index(), Some(1));
+    /// assert_eq!(cursor.peek_next(), Some(&3));
+    /// ```
+    #[inline]
+    pub fn cursor_front(&self) -> Cursor<'_, T, A> {
+        Cursor {
+            index: 0,
+            current: self.head,
+            list: self,
+        }
+    }
+
+    /// Provides a cursor with editing operations at the front element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..4).collect();
+    ///
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// cursor.insert_after(5);
+    /// assert_eq!(list, [1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, A> {
+        CursorMut {
+            index: 0,
+            current: self.head,
+            list: self,
+        }
+    }
+
+    /// Provides a cursor at the back element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = (1..4).collect();
+    ///
+    /// let mut cursor = list.cursor_back();
+    /// assert_eq!(cursor.current(), Some(&3));
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.index(), Some(1));
+    /// assert_eq!(cursor.peek_prev(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn cursor_back(&self) -> Cursor<'_, T, A> {
+        Cursor {
+            index: self.len.saturating_sub(1),
+            current: self.tail,
+            list: self,
+        }
+    }
+
+    /// Provides a cursor with editing operations at the back element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..4).collect();
+    ///
+    /// let mut cursor = list.cursor_back_mut();
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// cursor.insert_before(5);
+    /// assert_eq!(list, [1, 5, 3]);
+    /// ```
+    #[inline]
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, A> {
+        CursorMut {
+            index: self.len.saturating_sub(1),
+            current: self.tail,
+            list: self,
+        }
+    }
+
+    /// Provides a reference to the front element,
+    /// or `None` if the list is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// assert_eq!(list.front(), None);
+    ///
+    /// list.push_front(1);
+    /// assert_eq!(list.front(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &unsafe { node.as_ref() }.element)
+    }
+
+    /// Provides a mutable reference to the front element,
+    /// or `None` if the list is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..3).collect();
+    /// if let Some(x) = list.front_mut() {
+    ///     *x = 5;
+    /// }
+    /// assert_eq!(list, [5, 2]);
+    /// ```
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut unsafe { node.as_mut() }.element)
+    }
+
+    /// Provides a reference to the back element,
+    /// or `None` if the list is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// assert_eq!(list.back(), None);
+    ///
+    /// list.push_back(1);
+    /// assert_eq!(list.back(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.tail.as_ref().map(|node| &unsafe { node.as_ref() }.element)
+    }
+
+    /// Provides a mutable reference to the back element,
+    /// or `None` if the list is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..3).collect();
+    /// if let Some(x) = list.back_mut() {
+    ///     *x = 5;
+    /// }
+    /// assert_eq!(list, [1, 5]);
+    /// ```
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.as_mut().map(|node| &mut unsafe { node.as_mut() }.element)
+    }
+
+    /// Provides a reference to the element at position `index`,
+    /// or `None` if there is no element at that position.
+    ///
+    /// The list is walked from its closest end to `index`. This
+    /// operation should compute in *O*(min(`index`, `len` - `index`)) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = (1..4).collect();
+    /// assert_eq!(list.get(2), Some(&3));
+    /// assert_eq!(list.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.node_at(index).map(|node| unsafe { &(*node.as_ptr()).element })
+    }
+
+    /// Provides a mutable reference to the element at position `index`,
+    /// or `None` if there is no element at that position.
+    ///
+    /// This operation should compute in *O*(min(`index`, `len` - `index`))
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..4).collect();
+    /// if let Some(x) = list.get_mut(1) {
+    ///     *x = 7;
+    /// }
+    /// assert_eq!(list, [1, 7, 3]);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.node_at(index).map(|node| unsafe { &mut (*node.as_ptr()).element })
+    }
+
+    /// Adds an element first in the list.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_front(2);
+    /// list.push_front(1);
+    /// assert_eq!(list, [1, 2]);
+    /// ```
+    pub fn push_front(&mut self, element: T) {
+        let node = self.alloc_node(element);
+        unsafe { self.link_after(None, node) };
+    }
+
+    /// Appends an element to the back of the list.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(list, [1, 2]);
+    /// ```
+    pub fn push_back(&mut self, element: T) {
+        let node = self.alloc_node(element);
+        unsafe { self.link_after(self.tail, node) };
+    }
+
+    /// Adds an element first in the list and returns a handle to it.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    ///
+    /// let handle = list.push_front_handle(1);
+    /// list.push_front(0);
+    /// assert_eq!(unsafe { list.get_handle(handle) }, &1);
+    /// ```
+    pub fn push_front_handle(&mut self, element: T) -> NodeRef<T> {
+        let node = self.alloc_node(element);
+        unsafe { self.link_after(None, node) };
+        NodeRef { node }
+    }
+
+    /// Appends an element to the back of the list and returns a handle
+    /// to it.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    ///
+    /// let handle = list.push_back_handle(1);
+    /// list.push_back(2);
+    /// assert_eq!(unsafe { list.get_handle(handle) }, &1);
+    /// ```
+    pub fn push_back_handle(&mut self, element: T) -> NodeRef<T> {
+        let node = self.alloc_node(element);
+        unsafe { self.link_after(self.tail, node) };
+        NodeRef { node }
+    }
+
+    /// Provides a reference to the element of `handle`.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must have been returned by this list, and its element must
+    /// not have been removed since.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (0..3).collect();
+    ///
+    /// let handle = list.push_back_handle(3);
+    /// list.pop_front();
+    /// assert_eq!(unsafe { list.get_handle(handle) }, &3);
+    /// ```
+    #[inline]
+    pub unsafe fn get_handle(&self, handle: NodeRef<T>) -> &T {
+        &(*handle.node.as_ptr()).element
+    }
+
+    /// Removes the element of `handle` and returns it.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must have been returned by this list, and its element must
+    /// not have been removed since.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    ///
+    /// list.push_back(1);
+    /// let handle = list.push_back_handle(2);
+    /// list.push_back(3);
+    ///
+    /// assert_eq!(unsafe { list.remove_handle(handle) }, 2);
+    /// assert_eq!(list, [1, 3]);
+    /// ```
+    pub unsafe fn remove_handle(&mut self, handle: NodeRef<T>) -> T {
+        let node = self.unlink_node(handle.node);
+        self.free_node(node)
+    }
+
+    /// Removes the first element and returns it,
+    /// or `None` if the list is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..3).collect();
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        let node = self.head?;
+        unsafe {
+            let node = self.unlink_node(node);
+            Some(self.free_node(node))
+        }
+    }
+
+    /// Removes the last element and returns it,
+    /// or `None` if the list is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..3).collect();
+    /// assert_eq!(list.pop_back(), Some(2));
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        let node = self.tail?;
+        unsafe {
+            let node = self.unlink_node(node);
+            Some(self.free_node(node))
+        }
+    }
+
+    /// Removes all elements from the `DoublyLinkedList`.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..3).collect();
+    ///
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Returns `true` if the `DoublyLinkedList` contains an element equal
+    /// to the given value.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = (1..3).collect();
+    /// assert!(list.contains(&2));
+    /// assert!(!list.contains(&3));
+    /// ```
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|e| e == x)
+    }
+
+    /// Moves all elements from `other` to the end of the list.
+    ///
+    /// This reuses all the nodes from `other` and moves them into `self`.
+    /// After this operation, `other` becomes empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..3).collect();
+    /// let mut other: DoublyLinkedList<u32> = (3..5).collect();
+    ///
+    /// list.append(&mut other);
+    /// assert_eq!(list, [1, 2, 3, 4]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        if let Some(other_head) = other.head.take() {
+            match self.tail {
+                None => self.head = Some(other_head),
+                Some(tail) => unsafe {
+                    (*tail.as_ptr()).next = Some(other_head);
+                    (*other_head.as_ptr()).prev = Some(tail);
+                },
+            }
+            self.tail = other.tail.take();
+            self.len += mem::replace(&mut other.len, 0);
+        }
+    }
+
+    /// Moves all elements from `other` to the beginning of the list.
+    ///
+    /// This reuses all the nodes from `other` and moves them into `self`.
+    /// After this operation, `other` becomes empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (3..5).collect();
+    /// let mut other: DoublyLinkedList<u32> = (1..3).collect();
+    ///
+    /// list.prepend(&mut other);
+    /// assert_eq!(list, [1, 2, 3, 4]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn prepend(&mut self, other: &mut Self) {
+        if let Some(other_tail) = other.tail.take() {
+            unsafe { (*other_tail.as_ptr()).next = self.head; }
+            match self.head {
+                None => self.tail = Some(other_tail),
+                Some(head) => unsafe { (*head.as_ptr()).prev = Some(other_tail); },
+            }
+            self.head = other.head.take();
+            self.len += mem::replace(&mut other.len, 0);
+        }
+    }
+
+    /// Reverses the order of the elements in place.
+    ///
+    /// No element is moved nor allocated, only the links are changed.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..4).collect();
+    ///
+    /// list.reverse();
+    /// assert_eq!(list, [3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        let mut node = self.head;
+        while let Some(n) = node {
+            let n = unsafe { &mut *n.as_ptr() };
+            mem::swap(&mut n.next, &mut n.prev);
+            node = n.prev;
+        }
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Splits the list into two at the given index. Returns everything after
+    /// the given index, including the index.
+    ///
+    /// This operation should compute in *O*(min(`at`, `len` - `at`)) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` > `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..5).collect();
+    ///
+    /// let split = list.split_off(3);
+    /// assert_eq!(list, [1, 2, 3]);
+    /// assert_eq!(split, [4]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        A: Clone,
+    {
+        assert!(
+            at <= self.len,
+            "split index (is {}) should be <= len (is {})",
+            at,
+            self.len,
+        );
+
+        let mut list = Self::new_in(self.alloc.clone());
+        if at == 0 {
+            list.append(self);
+            return list;
+        }
+
+        let last = self.node_at(at - 1).unwrap();
+        let head = unsafe { (*last.as_ptr()).next.take() };
+        if let Some(head) = head {
+            unsafe { (*head.as_ptr()).prev = None; }
+            list.head = Some(head);
+            list.tail = self.tail;
+            list.len = self.len - at;
+        }
+        self.tail = Some(last);
+        self.len = at;
+        list
+    }
+
+    /// Inserts an element at position `index`.
+    ///
+    /// This operation should compute in *O*(min(`index`, `len` - `index`))
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` > `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..4).collect();
+    ///
+    /// list.insert(1, 7);
+    /// assert_eq!(list, [1, 7, 2, 3]);
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) {
+        assert!(
+            index <= self.len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            self.len,
+        );
+
+        let before = match index {
+            0 => None,
+            _ => self.node_at(index - 1),
+        };
+
+        let node = self.alloc_node(element);
+        unsafe { self.link_after(before, node) };
+    }
+
+    /// Inserts an element at position `index`, or returns an error if
+    /// `index` > `len`.
+    ///
+    /// This is the non-panicking counterpart of
+    /// [`insert`](DoublyLinkedList::insert). On error, `element` is dropped.
+    ///
+    /// This operation should compute in *O*(min(`index`, `len` - `index`))
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::{doubly_linked_list::DoublyLinkedList, errors::InsertError};
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    ///
+    /// assert_eq!(list.try_insert(0, 4), Ok(()));
+    /// assert_eq!(list.front(), Some(&4));
+    ///
+    /// assert_eq!(list.try_insert(3, 2), Err(InsertError { index: 3, len: 1 }));
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), InsertError> {
+        if index > self.len {
+            return Err(InsertError { index, len: self.len });
+        }
+        self.insert(index, element);
+        Ok(())
+    }
+
+    /// Removes the element at position `index` and returns it.
+    ///
+    /// This operation should compute in *O*(min(`index`, `len` - `index`))
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..4).collect();
+    ///
+    /// assert_eq!(list.remove(1), 2);
+    /// assert_eq!(list, [1, 3]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "removal index (is {}) should be < len (is {})",
+            index,
+            self.len,
+        );
+
+        unsafe {
+            let node = self.unlink_node(self.node_at(index).unwrap());
+            self.free_node(node)
+        }
+    }
+
+    /// Returns a view of the list implementing `defmt::Format`,
+    /// printing at most `max` elements.
+    ///
+    /// The `defmt::Format` implementation of `DoublyLinkedList` itself
+    /// prints at most [`DEFMT_MAX_ELEMENTS`](crate::DEFMT_MAX_ELEMENTS)
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list = DoublyLinkedList::from([1, 2, 3]);
+    ///
+    /// // Logs `[1, 2, ..]`
+    /// defmt::info!("{}", list.defmt_prefix(2));
+    /// ```
+    #[cfg(feature = "defmt")]
+    #[inline]
+    pub fn defmt_prefix(&self, max: usize) -> DefmtPrefix<'_, T, A> {
+        DefmtPrefix { list: self, max }
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    /// Creates an empty `DoublyLinkedList`.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for DoublyLinkedList<T, A> {
+    /// Returns a deep copy of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = (1..3).collect();
+    ///
+    /// let mut copy = list.clone();
+    /// copy.push_back(3);
+    /// assert_eq!(list, [1, 2]);
+    /// assert_eq!(copy, [1, 2, 3]);
+    /// ```
+    fn clone(&self) -> Self {
+        let mut list = Self::new_in(self.alloc.clone());
+        list.extend(self.iter().cloned());
+        list
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for DoublyLinkedList<T, A> {
+    /// Formats the list like a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = (1..4).collect();
+    /// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialOrd, A: Allocator> PartialOrd for DoublyLinkedList<T, A> {
+    /// Compares the lists lexicographically.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<T: Ord, A: Allocator> Ord for DoublyLinkedList<T, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other)
+    }
+}
+
+impl<T: Hash, A: Allocator> Hash for DoublyLinkedList<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for element in self {
+            element.hash(state);
+        }
+    }
+}
+
+#[cfg(not(feature = "may_dangle"))]
+impl<T, A: Allocator> Drop for DoublyLinkedList<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+// The elements are only dropped, never accessed: `marker` tells
+// dropck that they are owned.
+#[cfg(feature = "may_dangle")]
+unsafe impl<#[may_dangle] T, A: Allocator> Drop for DoublyLinkedList<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for DoublyLinkedList<T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for DoublyLinkedList<T, A> {}
+
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Send for Cursor<'_, T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Cursor<'_, T, A> {}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for CursorMut<'_, T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for CursorMut<'_, T, A> {}
+
+// A `NodeRef` can only be dereferenced through its list.
+unsafe impl<T> Send for NodeRef<T> {}
+
+unsafe impl<T> Sync for NodeRef<T> {}
+
+impl<T, A: Allocator> Index<usize> for DoublyLinkedList<T, A> {
+    type Output = T;
+
+    /// Provides a reference to the element at position `index`.
+    /// [`get`](DoublyLinkedList::get) doesn't panic if there is no element
+    /// at that position.
+    ///
+    /// This operation should compute in *O*(min(`index`, `len` - `index`))
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = (1..4).collect();
+    /// assert_eq!(list[0], 1);
+    /// assert_eq!(list[2], 3);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(element) => element,
+            None => panic!("index out of bounds: the len is {} but the index is {}", self.len, index),
+        }
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for DoublyLinkedList<T, A> {
+    /// Provides a mutable reference to the element at position `index`.
+    /// [`get_mut`](DoublyLinkedList::get_mut) doesn't panic if there is no
+    /// element at that position.
+    ///
+    /// This operation should compute in *O*(min(`index`, `len` - `index`))
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list: DoublyLinkedList<u32> = (1..4).collect();
+    /// list[1] = 5;
+    /// assert_eq!(list, [1, 5, 3]);
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(element) => element,
+            None => panic!("index out of bounds: the len is {} but the index is {}", len, index),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            let node = unsafe { &*node.as_ptr() };
+            self.head = node.next;
+            self.len -= 1;
+            &node.element
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.map(|node| {
+            let node = unsafe { &*node.as_ptr() };
+            self.tail = node.prev;
+            self.len -= 1;
+            &node.element
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+impl<T> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter").field(&self.len).finish()
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            let node = unsafe { &mut *node.as_ptr() };
+            self.head = node.next;
+            self.len -= 1;
+            &mut node.element
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.map(|node| {
+            let node = unsafe { &mut *node.as_ptr() };
+            self.tail = node.prev;
+            self.len -= 1;
+            &mut node.element
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T> fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IterMut").field(&self.len).finish()
+    }
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for IntoIter<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.list).finish()
+    }
+}
+
+impl<'a, T, A: Allocator> Cursor<'a, T, A> {
+    /// Returns the cursor position index within the `DoublyLinkedList`.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Moves the cursor to the next element of the `DoublyLinkedList`.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will
+    /// move it to the first element of the `DoublyLinkedList`. If it is
+    /// pointing to the last element of the `DoublyLinkedList` then this will
+    /// move it to the "ghost" non-element.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(current) => {
+                self.current = unsafe { current.as_ref() }.next;
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Moves the cursor to the previous element of the `DoublyLinkedList`.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will
+    /// move it to the last element of the `DoublyLinkedList`. If it is
+    /// pointing to the first element of the `DoublyLinkedList` then this will
+    /// move it to the "ghost" non-element.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(current) => {
+                self.current = unsafe { current.as_ref() }.prev;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Returns a reference to the element that the cursor is currently
+    /// pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[inline]
+    pub fn current(&self) -> Option<&'a T> {
+        self.current.map(|current| unsafe { &(*current.as_ptr()).element })
+    }
+
+    /// Returns a reference to the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element of the `DoublyLinkedList`. If it is pointing to the
+    /// last element of the `DoublyLinkedList` then this returns `None`.
+    #[inline]
+    pub fn peek_next(&self) -> Option<&'a T> {
+        let next = match self.current {
+            Some(current) => unsafe { current.as_ref() }.next,
+            None => self.list.head,
+        };
+        next.map(|next| unsafe { &(*next.as_ptr()).element })
+    }
+
+    /// Returns a reference to the previous element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the last element of the `DoublyLinkedList`. If it is pointing to the
+    /// first element of the `DoublyLinkedList` then this returns `None`.
+    #[inline]
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        let prev = match self.current {
+            Some(current) => unsafe { current.as_ref() }.prev,
+            None => self.list.tail,
+        };
+        prev.map(|prev| unsafe { &(*prev.as_ptr()).element })
+    }
+}
+
+impl<T, A: Allocator> Clone for Cursor<'_, T, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Cursor { ..*self }
+    }
+}
+
+impl<T> Clone for NodeRef<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodeRef<T> {}
+
+impl<T> PartialEq for NodeRef<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node
+    }
+}
+
+impl<T> Eq for NodeRef<T> {}
+
+impl<T> Hash for NodeRef<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for NodeRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NodeRef").field(&self.node).finish()
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Cursor<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Cursor")
+            .field(self.list)
+            .field(&self.index())
+            .finish()
+    }
+}
+
+impl<'a, T, A: Allocator> CursorMut<'a, T, A> {
+    /// Returns a read-only cursor pointing to the current element.
+    ///
+    /// The lifetime of the returned `Cursor` is bound to that of the
+    /// `CursorMut`, which means it cannot outlive the `CursorMut` and that the
+    /// `CursorMut` is frozen for the lifetime of the `Cursor`.
+    #[inline]
+    pub fn as_cursor(&self) -> Cursor<'_, T, A> {
+        Cursor {
+            index: self.index,
+            current: self.current,
+            list: self.list,
+        }
+    }
+
+    /// Returns the cursor position index within the `DoublyLinkedList`.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Moves the cursor to the next element of the `DoublyLinkedList`.
+    ///
+    /// See [`Cursor::move_next`].
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(current) => {
+                self.current = unsafe { current.as_ref() }.next;
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Moves the cursor to the previous element of the `DoublyLinkedList`.
+    ///
+    /// See [`Cursor::move_prev`].
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(current) => {
+                self.current = unsafe { current.as_ref() }.prev;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Returns a reference to the element that the cursor is currently
+    /// pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[inline]
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|current| unsafe { &mut (*current.as_ptr()).element })
+    }
+
+    /// Returns a reference to the next element.
+    ///
+    /// See [`Cursor::peek_next`].
+    #[inline]
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            Some(current) => unsafe { current.as_ref() }.next,
+            None => self.list.head,
+        };
+        next.map(|next| unsafe { &mut (*next.as_ptr()).element })
+    }
+
+    /// Returns a reference to the previous element.
+    ///
+    /// See [`Cursor::peek_prev`].
+    #[inline]
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = match self.current {
+            Some(current) => unsafe { current.as_ref() }.prev,
+            None => self.list.tail,
+        };
+        prev.map(|prev| unsafe { &mut (*prev.as_ptr()).element })
+    }
+
+    /// Inserts a new element into the `DoublyLinkedList` after the current
+    /// one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new
+    /// element is inserted at the front of the `DoublyLinkedList`.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn insert_after(&mut self, item: T) {
+        let node = self.list.alloc_node(item);
+        unsafe { self.list.link_after(self.current, node) };
+        if self.current.is_none() {
+            self.index += 1;
+        }
+    }
+
+    /// Inserts a new element into the `DoublyLinkedList` before the current
+    /// one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new
+    /// element is inserted at the end of the `DoublyLinkedList`.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn insert_before(&mut self, item: T) {
+        let prev = match self.current {
+            Some(current) => unsafe { current.as_ref() }.prev,
+            None => self.list.tail,
+        };
+        let node = self.list.alloc_node(item);
+        unsafe { self.list.link_after(prev, node) };
+        self.index += 1;
+    }
+
+    /// Removes the current element from the `DoublyLinkedList`.
+    ///
+    /// The element that was removed is returned, and the cursor is
+    /// moved to point to the next element in the `DoublyLinkedList`.
+    ///
+    /// If the cursor is currently pointing to the "ghost" non-element then no
+    /// element is removed and `None` is returned.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current?;
+        self.current = unsafe { current.as_ref() }.next;
+        unsafe {
+            let current = self.list.unlink_node(current);
+            Some(self.list.free_node(current))
+        }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for CursorMut<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CursorMut")
+            .field(&self.list)
+            .field(&self.index())
+            .finish()
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for DoublyLinkedList<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    /// Consumes the list into an iterator yielding elements by value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = (1..4).collect();
+    ///
+    /// let mut iter = list.into_iter();
+    /// assert_eq!(iter.next_back(), Some(3));
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> IntoIter<T, A> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a DoublyLinkedList<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut DoublyLinkedList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    /// Creates a `DoublyLinkedList` from an iterator,
+    /// the first element being at the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list: DoublyLinkedList<u32> = (1..4).collect();
+    /// assert_eq!(list.front(), Some(&1));
+    /// assert_eq!(list.back(), Some(&3));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> From<Vec<T>> for DoublyLinkedList<T> {
+    /// Creates a `DoublyLinkedList` from a `Vec`,
+    /// the first element being at the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list = DoublyLinkedList::from(vec![1, 2, 3]);
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for DoublyLinkedList<T> {
+    /// Creates a `DoublyLinkedList` from an array,
+    /// the first element being at the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let list = DoublyLinkedList::from([1, 2, 3]);
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        IntoIterator::into_iter(array).collect()
+    }
+}
+
+impl<T, A: Allocator> From<DoublyLinkedList<T, A>> for Vec<T> {
+    /// Converts a `DoublyLinkedList` into a `Vec`,
+    /// the front element being first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let vec = Vec::from(DoublyLinkedList::from([1, 2, 3]));
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    fn from(list: DoublyLinkedList<T, A>) -> Self {
+        let mut vec = Vec::with_capacity(list.len);
+        vec.extend(list);
+        vec
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for DoublyLinkedList<T, A> {
+    /// Appends the elements of an iterator to the back of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_back(1);
+    ///
+    /// list.extend(vec![2, 3]);
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.push_back(element);
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy, A: Allocator> Extend<&'a T> for DoublyLinkedList<T, A> {
+    /// Appends copies of the elements of an iterator
+    /// to the back of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.push_back(1);
+    ///
+    /// list.extend(&[2, 3]);
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// A view of a [`DoublyLinkedList`] implementing `defmt::Format`,
+/// printing only the first elements.
+///
+/// This `struct` is created by [`DoublyLinkedList::defmt_prefix`].
+#[cfg(feature = "defmt")]
+pub struct DefmtPrefix<'a, T, A: Allocator = Global> {
+    list: &'a DoublyLinkedList<T, A>,
+    max: usize,
+}
+
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format, A: Allocator> defmt::Format for DefmtPrefix<'_, T, A> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        crate::format::defmt_prefix(f, self.list.iter(), self.max);
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format, A: Allocator> defmt::Format for DoublyLinkedList<T, A> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        self.defmt_prefix(crate::DEFMT_MAX_ELEMENTS).format(f)
+    }
+}
+
+impl_slice_eq! { [A: Allocator, B: Allocator] DoublyLinkedList<T, A>, DoublyLinkedList<U, B> }
+impl_slice_eq! { [A: Allocator] DoublyLinkedList<T, A>, [U] }
+impl_slice_eq! { [A: Allocator] DoublyLinkedList<T, A>, &[U] }
+impl_slice_eq! { [A: Allocator] DoublyLinkedList<T, A>, &mut [U] }
+impl_slice_eq! { [A: Allocator, const N: usize] DoublyLinkedList<T, A>, [U; N] }
+impl_slice_eq! { [A: Allocator] DoublyLinkedList<T, A>, Vec<U> }
+impl_slice_eq! { [A: Allocator] [T], DoublyLinkedList<U, A> }
+impl_slice_eq! { [A: Allocator] &[T], DoublyLinkedList<U, A> }
+impl_slice_eq! { [A: Allocator] &mut [T], DoublyLinkedList<U, A> }
+impl_slice_eq! { [A: Allocator, const N: usize] [T; N], DoublyLinkedList<U, A> }
+impl_slice_eq! { [A: Allocator] Vec<T>, DoublyLinkedList<U, A> }
+impl_slice_eq! { [A: Allocator, B: Allocator] DoublyLinkedList<T, A>, LinkedList<U, B> }
+impl_slice_eq! { [A: Allocator, B: Allocator] LinkedList<T, A>, DoublyLinkedList<U, B> }
+
+impl<T: Eq, A: Allocator> Eq for DoublyLinkedList<T, A> {}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use alloc::{format, vec};
+    use super::*;
+    use crate::test_utils::DropCounter;
+
+    /// Checks that `prev` mirrors `next` and that `len` and `tail` match.
+    fn check_links<T, A: Allocator>(list: &DoublyLinkedList<T, A>) {
+        let mut prev = None;
+        let mut node = list.head;
+        let mut len = 0;
+        while let Some(n) = node {
+            assert_eq!(unsafe { n.as_ref() }.prev, prev);
+            prev = node;
+            node = unsafe { n.as_ref() }.next;
+            len += 1;
+        }
+        assert_eq!(list.tail, prev);
+        assert_eq!(list.len, len);
+    }
+
+    #[test]
+    fn empty() {
+        let mut list: DoublyLinkedList<u32> = DoublyLinkedList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back_mut(), None);
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.iter().next_back(), None);
+        check_links(&list);
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut list = DoublyLinkedList::new();
+        for i in 0..4 {
+            list.push_back(i);
+            list.push_front(-i);
+            check_links(&list);
+        }
+        assert_eq!(list, [-3, -2, -1, 0, 0, 1, 2, 3]);
+
+        for i in (0..4).rev() {
+            assert_eq!(list.pop_back(), Some(i));
+            assert_eq!(list.pop_front(), Some(-i));
+            check_links(&list);
+        }
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn links() {
+        let mut list: DoublyLinkedList<u32> = (0..8).collect();
+        list.insert(3, 10);
+        list.insert(7, 11);
+        list.insert(0, 12);
+        check_links(&list);
+        assert_eq!(list, [12, 0, 1, 2, 10, 3, 4, 5, 11, 6, 7]);
+
+        assert_eq!(list.remove(9), 6);
+        assert_eq!(list.remove(1), 0);
+        check_links(&list);
+
+        list.reverse();
+        check_links(&list);
+        assert_eq!(list, [7, 11, 5, 4, 3, 10, 2, 1, 12]);
+
+        let mut back = list.split_off(6);
+        check_links(&list);
+        check_links(&back);
+        assert_eq!(back, [2, 1, 12]);
+        list.prepend(&mut back);
+        check_links(&list);
+        list.append(&mut back);
+        assert_eq!(list, [2, 1, 12, 7, 11, 5, 4, 3, 10]);
+
+        let all = list.split_off(0);
+        check_links(&list);
+        check_links(&all);
+        assert_eq!(all.len(), 9);
+    }
+
+    #[test]
+    fn handles() {
+        let mut list = DoublyLinkedList::new();
+        let b = list.push_back_handle('b');
+        let a = list.push_front_handle('a');
+        let c = list.push_back_handle('c');
+        list.push_back('d');
+        assert_ne!(a, b);
+        assert_eq!(a, a.clone());
+
+        unsafe {
+            assert_eq!(list.get_handle(a), &'a');
+            assert_eq!(list.get_handle(c), &'c');
+
+            assert_eq!(list.remove_handle(b), 'b');
+            check_links(&list);
+            assert_eq!(list, ['a', 'c', 'd']);
+            assert_eq!(list.remove_handle(a), 'a');
+            check_links(&list);
+            assert_eq!(list.front(), Some(&'c'));
+        }
+
+        let e = list.push_back_handle('e');
+        unsafe {
+            assert_eq!(list.remove_handle(e), 'e');
+            check_links(&list);
+            assert_eq!(list.back(), Some(&'d'));
+            list.push_back('f');
+            assert_eq!(list.remove_handle(c), 'c');
+            check_links(&list);
+        }
+        assert_eq!(list, ['d', 'f']);
+    }
+
+    #[test]
+    fn try_insert() {
+        let mut list: DoublyLinkedList<u32> = DoublyLinkedList::new();
+
+        assert_eq!(list.try_insert(1, 3), Err(InsertError { index: 1, len: 0 }));
+        assert_eq!(list.try_insert(0, 3), Ok(()));
+        assert_eq!(list.try_insert(1, 5), Ok(()));
+        assert_eq!(list.try_insert(3, 5), Err(InsertError { index: 3, len: 2 }));
+        check_links(&list);
+        assert_eq!(list, [3, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 3) should be <= len (is 2)")]
+    fn insert_out_of_bounds() {
+        let mut list: DoublyLinkedList<u32> = (0..2).collect();
+        list.insert(3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn index_out_of_bounds() {
+        let list: DoublyLinkedList<u32> = (0..2).collect();
+        let _ = list[2];
+    }
+
+    #[test]
+    fn double_ended() {
+        let mut list: DoublyLinkedList<u32> = (0..5).collect();
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), [&3, &2, &1]);
+
+        let mut iter = list.iter_mut();
+        *iter.next_back().unwrap() = 10;
+        *iter.next().unwrap() = 20;
+        let mut iter = iter.rev();
+        assert_eq!(iter.next(), Some(&mut 3));
+        assert_eq!(iter.next_back(), Some(&mut 1));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(list, [20, 1, 2, 3, 10]);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next_back(), Some(10));
+        assert_eq!(iter.next(), Some(20));
+        assert_eq!(iter.rev().collect::<Vec<_>>(), [3, 2, 1]);
+    }
+
+    #[test]
+    fn cursor() {
+        let empty: DoublyLinkedList<u32> = DoublyLinkedList::new();
+        let mut cursor = empty.cursor_back();
+        assert_eq!(cursor.current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.peek_prev(), None);
+
+        let list: DoublyLinkedList<u32> = (0..3).collect();
+        let mut cursor = list.cursor_back();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.peek_prev(), Some(&1));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&0));
+        assert_eq!(cursor.peek_prev(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_prev(), Some(&2));
+        assert_eq!(cursor.peek_next(), Some(&0));
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+    }
+
+    #[test]
+    fn cursor_mut() {
+        let mut list: DoublyLinkedList<u32> = (0..3).collect();
+        let mut cursor = list.cursor_back_mut();
+        cursor.insert_before(5);
+        assert_eq!(cursor.index(), Some(3));
+        assert_eq!(cursor.peek_prev(), Some(&mut 5));
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 0));
+        cursor.insert_before(6);
+        assert_eq!(cursor.index(), Some(1));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        cursor.insert_before(7);
+        cursor.insert_after(8);
+        assert_eq!(cursor.peek_prev(), Some(&mut 7));
+        assert_eq!(cursor.peek_next(), Some(&mut 8));
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(6));
+        assert_eq!(cursor.remove_current(), Some(7));
+        assert_eq!(cursor.as_cursor().index(), None);
+        check_links(&list);
+        assert_eq!(list, [8, 6, 0, 1, 5, 2]);
+    }
+
+    #[test]
+    fn drop() {
+        let drops = Cell::new(0);
+        let mut list = DoublyLinkedList::new();
+        list.push_back(DropCounter(&drops));
+        list.push_front(DropCounter(&drops));
+        list.push_back(DropCounter(&drops));
+        mem::drop(list.pop_back());
+        assert_eq!(drops.get(), 1);
+        mem::drop(list);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn traits() {
+        let list: DoublyLinkedList<u32> = (0..3).collect();
+        assert_eq!(list.clone(), list);
+        assert_eq!(vec![0, 1, 2], list);
+        assert_eq!(Vec::from(list.clone()), [0, 1, 2]);
+        let greater = DoublyLinkedList::from([0, 2]);
+        assert!(list < greater);
+        assert_eq!(format!("{:?}", list), "[0, 1, 2]");
+        assert_eq!(format!("{:?}", list.iter()), "Iter(3)");
+        assert_eq!(format!("{:?}", list.cursor_back()), "Cursor([0, 1, 2], Some(2))");
+
+        let singly: LinkedList<u32> = (0..3).collect();
+        assert!(list == singly);
+        assert!(singly == list);
+        let shorter = LinkedList::from([0, 1]);
+        assert!(list != shorter);
+    }
+
+    #[test]
+    fn macro_literal() {
+        let list: DoublyLinkedList<u32> = doubly_linked_list![];
+        assert_eq!(list.len(), 0);
+
+        let list = doubly_linked_list![4, 5, 6,];
+        assert_eq!(list, [4, 5, 6]);
+
+        let list = doubly_linked_list![7; 3];
+        assert_eq!(list, [7, 7, 7]);
+    }
+
+    #[test]
+    fn send_sync() {
+        fn is_send<T: Send>(_: &T) {}
+        fn is_sync<T: Sync>(_: &T) {}
+
+        let mut list: DoublyLinkedList<u32> = (0..3).collect();
+        is_send(&list);
+        is_sync(&list);
+        is_send(&list.iter());
+        is_sync(&list.iter_mut());
+        is_send(&list.cursor_front());
+        is_sync(&list.cursor_back_mut());
+
+        extern crate std;
+        let sum = std::thread::spawn(move || list.iter().rev().sum::<u32>()).join();
+        assert_eq!(sum.unwrap(), 3);
+    }
+}
@@ -0,0 +1,221 @@
+//! Error types returned by the fallible APIs of the crate.
+//!
+//! Each fallible operation returns the most precise error type,
+//! all of which can be converted into the shared [`Error`] with `?`.
+
+use core::{alloc::Layout, fmt};
+
+/// An error returned by the fallible operations of the crate.
+///
+/// # Examples
+///
+/// ```
+/// use collections::errors::{AllocError, Error};
+///
+/// fn alloc() -> Result<(), AllocError> {
+///     Err(AllocError)
+/// }
+///
+/// fn run() -> Result<(), Error> {
+///     alloc()?;
+///     Ok(())
+/// }
+///
+/// assert_eq!(run(), Err(Error::Alloc(AllocError)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// An index was out of the bounds of the collection.
+    IndexOutOfBounds {
+        /// The offending index.
+        index: usize,
+        /// The length of the collection.
+        len: usize,
+    },
+    /// A fixed-capacity collection was full.
+    Capacity(CapacityError),
+    /// The allocator failed.
+    Alloc(AllocError),
+    /// Reserving capacity failed.
+    TryReserve(TryReserveError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::IndexOutOfBounds { index, len } => write!(
+                f,
+                "index out of bounds: the len is {} but the index is {}",
+                len, index,
+            ),
+            Error::Capacity(e) => e.fmt(f),
+            Error::Alloc(e) => e.fmt(f),
+            Error::TryReserve(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<T> From<CapacityError<T>> for Error {
+    #[inline]
+    fn from(e: CapacityError<T>) -> Self {
+        Error::Capacity(e.simplify())
+    }
+}
+
+impl From<InsertError> for Error {
+    #[inline]
+    fn from(e: InsertError) -> Self {
+        Error::IndexOutOfBounds {
+            index: e.index,
+            len: e.len,
+        }
+    }
+}
+
+impl From<AllocError> for Error {
+    #[inline]
+    fn from(e: AllocError) -> Self {
+        Error::Alloc(e)
+    }
+}
+
+impl From<TryReserveError> for Error {
+    #[inline]
+    fn from(e: TryReserveError) -> Self {
+        Error::TryReserve(e)
+    }
+}
+
+/// The error returned when adding an element to a full
+/// fixed-capacity collection.
+///
+/// It holds the element that couldn't be added.
+///
+/// # Examples
+///
+/// ```
+/// use collections::errors::CapacityError;
+///
+/// let e = CapacityError::new(5);
+/// assert_eq!(e.to_string(), "insufficient capacity");
+/// assert_eq!(e.element(), 5);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T = ()> {
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Creates a `CapacityError` holding `element`.
+    #[inline]
+    pub const fn new(element: T) -> Self {
+        Self { element }
+    }
+
+    /// Returns the element that couldn't be added.
+    #[inline]
+    pub fn element(self) -> T {
+        self.element
+    }
+
+    /// Drops the element, keeping only the error.
+    #[inline]
+    pub fn simplify(self) -> CapacityError {
+        CapacityError { element: () }
+    }
+}
+
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CapacityError { .. }")
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
+
+/// The error returned when inserting an element at an index
+/// greater than the length of the collection.
+///
+/// # Examples
+///
+/// ```
+/// use collections::errors::{Error, InsertError};
+///
+/// let e = InsertError { index: 3, len: 1 };
+/// assert_eq!(e.to_string(), "insertion index (is 3) should be <= len (is 1)");
+/// assert_eq!(Error::from(e), Error::IndexOutOfBounds { index: 3, len: 1 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsertError {
+    /// The offending index.
+    pub index: usize,
+    /// The length of the collection.
+    pub len: usize,
+}
+
+impl fmt::Display for InsertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "insertion index (is {}) should be <= len (is {})",
+            self.index, self.len,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InsertError {}
+
+/// The error returned when the allocator fails to provide memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocError;
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AllocError {}
+
+/// The error returned by `try_reserve` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested capacity exceeds the maximum of the collection,
+    /// `isize::MAX` bytes or `usize::MAX` elements.
+    CapacityOverflow,
+    /// The allocator failed to provide memory.
+    AllocError {
+        /// The layout of the allocation request that failed.
+        layout: Layout,
+    },
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")?;
+        match self {
+            TryReserveError::CapacityOverflow => {
+                f.write_str(" because the computed capacity exceeded the collection's maximum")
+            }
+            TryReserveError::AllocError { .. } => {
+                f.write_str(" because the memory allocator returned an error")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryReserveError {}
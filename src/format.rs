@@ -0,0 +1,29 @@
+//! `defmt` formatting shared by the collections.
+
+/// Number of elements printed by the `defmt::Format` implementations
+/// of the collections, the remaining ones being elided.
+pub const DEFMT_MAX_ELEMENTS: usize = 8;
+
+/// Writes the elements like a slice, eliding the ones after the
+/// first `max`.
+pub(crate) fn defmt_prefix<'a, T, I>(f: defmt::Formatter<'_>, elements: I, max: usize)
+where
+    T: defmt::Format + 'a,
+    I: ExactSizeIterator<Item = &'a T>,
+{
+    let len = elements.len();
+    defmt::write!(f, "[");
+    for (i, element) in elements.take(max).enumerate() {
+        if i != 0 {
+            defmt::write!(f, ", ");
+        }
+        defmt::write!(f, "{}", element);
+    }
+    if len > max {
+        if max != 0 {
+            defmt::write!(f, ", ");
+        }
+        defmt::write!(f, "..");
+    }
+    defmt::write!(f, "]");
+}
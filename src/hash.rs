@@ -0,0 +1,315 @@
+//! Hashing support for hash-based collections.
+//!
+//! The default hasher is SipHash-1-3, keyed per instance of
+//! [`RandomState`] so that the iteration order and collision
+//! patterns of a collection can't be predicted by an attacker.
+//! [`FixedState`] gives reproducible hashes instead, for tests and
+//! golden files. Any other [`BuildHasher`] can be used as well.
+
+use core::hash::{BuildHasher, Hasher};
+
+/// An implementation of SipHash-1-3.
+///
+/// This is the same algorithm as `std`'s `DefaultHasher`,
+/// usable without `std`.
+///
+/// # Examples
+///
+/// ```
+/// use core::hash::Hasher;
+/// use collections::hash::SipHasher13;
+///
+/// let mut a = SipHasher13::new_with_keys(1, 2);
+/// let mut b = SipHasher13::new_with_keys(1, 2);
+/// a.write(b"hello");
+/// b.write(b"hel");
+/// b.write(b"lo");
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SipHasher13 {
+    k0: u64,
+    k1: u64,
+    length: usize,
+    state: State,
+    /// Unprocessed bytes, little-endian.
+    tail: u64,
+    /// Number of valid bytes in `tail`.
+    ntail: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+}
+
+impl State {
+    #[inline]
+    fn round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13);
+        self.v1 ^= self.v0;
+        self.v0 = self.v0.rotate_left(32);
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(16);
+        self.v3 ^= self.v2;
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(21);
+        self.v3 ^= self.v0;
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(17);
+        self.v1 ^= self.v2;
+        self.v2 = self.v2.rotate_left(32);
+    }
+
+    #[inline]
+    fn compress(&mut self, m: u64) {
+        self.v3 ^= m;
+        self.round();
+        self.v0 ^= m;
+    }
+}
+
+impl SipHasher13 {
+    /// Creates a `SipHasher13` keyed with `k0` and `k1`.
+    #[inline]
+    pub fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self {
+            k0,
+            k1,
+            length: 0,
+            state: State {
+                v0: k0 ^ 0x736f_6d65_7073_6575,
+                v1: k1 ^ 0x646f_7261_6e64_6f6d,
+                v2: k0 ^ 0x6c79_6765_6e65_7261,
+                v3: k1 ^ 0x7465_6462_7974_6573,
+            },
+            tail: 0,
+            ntail: 0,
+        }
+    }
+
+    /// Returns the keys this hasher was created with.
+    #[inline]
+    pub fn keys(&self) -> (u64, u64) {
+        (self.k0, self.k1)
+    }
+}
+
+impl Default for SipHasher13 {
+    /// Creates a `SipHasher13` with both keys set to 0.
+    #[inline]
+    fn default() -> Self {
+        Self::new_with_keys(0, 0)
+    }
+}
+
+impl Hasher for SipHasher13 {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len());
+
+        if self.ntail != 0 {
+            let needed = 8 - self.ntail;
+            let fill = needed.min(bytes.len());
+            self.tail |= read_le(&bytes[..fill]) << (8 * self.ntail);
+            bytes = &bytes[fill..];
+            if fill < needed {
+                self.ntail += fill;
+                return;
+            }
+            self.state.compress(self.tail);
+            self.tail = 0;
+            self.ntail = 0;
+        }
+
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.state.compress(read_le(chunk));
+        }
+        let rest = chunks.remainder();
+        self.tail = read_le(rest);
+        self.ntail = rest.len();
+    }
+
+    fn finish(&self) -> u64 {
+        let mut state = self.state;
+        let b = ((self.length as u64 & 0xff) << 56) | self.tail;
+        state.compress(b);
+        state.v2 ^= 0xff;
+        state.round();
+        state.round();
+        state.round();
+        state.v0 ^ state.v1 ^ state.v2 ^ state.v3
+    }
+}
+
+/// Reads up to 8 bytes as a little-endian integer.
+#[inline]
+fn read_le(bytes: &[u8]) -> u64 {
+    bytes.iter().rev().fold(0, |acc, &b| (acc << 8) | b as u64)
+}
+
+/// The default [`BuildHasher`] of the hash-based collections,
+/// creating [`SipHasher13`]s.
+///
+/// Every `RandomState` created with [`new`](RandomState::new) gets
+/// its own random keys, which requires the `std` feature.
+/// Without it, keys have to be provided with
+/// [`with_keys`](RandomState::with_keys).
+///
+/// # Examples
+///
+/// ```
+/// use core::hash::{BuildHasher, Hasher};
+/// use collections::hash::RandomState;
+///
+/// let state = RandomState::with_keys(3, 4);
+/// let mut a = state.build_hasher();
+/// let mut b = state.build_hasher();
+/// a.write_u32(12);
+/// b.write_u32(12);
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RandomState {
+    k0: u64,
+    k1: u64,
+}
+
+impl RandomState {
+    /// Creates a `RandomState` with random keys.
+    ///
+    /// The keys are derived from `std`'s `RandomState`,
+    /// so two calls never return the same keys.
+    #[cfg(feature = "std")]
+    pub fn new() -> Self {
+        let state = std::collections::hash_map::RandomState::new();
+        let mut hasher = state.build_hasher();
+        hasher.write_u8(0);
+        let k0 = hasher.finish();
+        hasher.write_u8(1);
+        let k1 = hasher.finish();
+        Self { k0, k1 }
+    }
+
+    /// Creates a `RandomState` with fixed keys.
+    #[inline]
+    pub fn with_keys(k0: u64, k1: u64) -> Self {
+        Self { k0, k1 }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for RandomState {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for RandomState {
+    type Hasher = SipHasher13;
+
+    #[inline]
+    fn build_hasher(&self) -> SipHasher13 {
+        SipHasher13::new_with_keys(self.k0, self.k1)
+    }
+}
+
+/// A [`BuildHasher`] creating [`SipHasher13`]s with fixed keys.
+///
+/// Unlike [`RandomState`], the hashes are the same across runs and
+/// across instances, making iteration order of hash-based collections
+/// deterministic. It should not be used with untrusted keys.
+///
+/// # Examples
+///
+/// ```
+/// use core::hash::{BuildHasher, Hasher};
+/// use collections::hash::FixedState;
+///
+/// let mut a = FixedState::default().build_hasher();
+/// let mut b = FixedState::default().build_hasher();
+/// a.write_u32(12);
+/// b.write_u32(12);
+/// assert_eq!(a.finish(), b.finish());
+///
+/// let mut c = FixedState::with_seed(1).build_hasher();
+/// c.write_u32(12);
+/// assert_ne!(a.finish(), c.finish());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FixedState {
+    seed: u64,
+}
+
+impl FixedState {
+    /// Creates a `FixedState` from a seed.
+    #[inline]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl BuildHasher for FixedState {
+    type Hasher = SipHasher13;
+
+    #[inline]
+    fn build_hasher(&self) -> SipHasher13 {
+        SipHasher13::new_with_keys(self.seed, !self.seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::{collections::hash_map::DefaultHasher, vec::Vec};
+    use super::*;
+
+    #[test]
+    fn matches_std() {
+        let bytes: Vec<u8> = (0..64).collect();
+        for len in 0..bytes.len() {
+            let mut std_hasher = DefaultHasher::new();
+            std_hasher.write(&bytes[..len]);
+            let mut hasher = SipHasher13::default();
+            hasher.write(&bytes[..len]);
+            assert_eq!(hasher.finish(), std_hasher.finish(), "length {}", len);
+        }
+    }
+
+    #[test]
+    fn split_writes() {
+        let bytes: Vec<u8> = (0..40).collect();
+        let mut whole = SipHasher13::new_with_keys(5, 6);
+        whole.write(&bytes);
+        for split in [1, 3, 8, 11, 16, 39].iter().copied() {
+            let mut parts = SipHasher13::new_with_keys(5, 6);
+            for chunk in bytes.chunks(split) {
+                parts.write(chunk);
+            }
+            assert_eq!(parts.finish(), whole.finish(), "split {}", split);
+        }
+    }
+
+    #[test]
+    fn keys_matter() {
+        let mut a = RandomState::with_keys(0, 1).build_hasher();
+        let mut b = RandomState::with_keys(1, 0).build_hasher();
+        a.write_u64(42);
+        b.write_u64(42);
+        assert_ne!(a.finish(), b.finish());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn random_keys() {
+        let a = RandomState::new().build_hasher();
+        let b = RandomState::new().build_hasher();
+        assert_ne!(a.keys(), b.keys());
+    }
+}
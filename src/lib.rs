@@ -1,8 +1,44 @@
 #![no_std]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#![cfg_attr(feature = "may_dangle", feature(dropck_eyepatch))]
 #![warn(missing_docs, rust_2018_idioms)]
 
 //! Collections types.
+//!
+//! Types needing a global allocator are gated behind the `alloc`
+//! feature, enabled by default. With `default-features = false`,
+//! the crate doesn't depend on `alloc` at all. The `std` feature
+//! enables randomly seeded hashing. The `allocator_api` feature,
+//! requiring a nightly compiler, makes the collections usable with
+//! any `core::alloc::Allocator`. The `may_dangle` feature, also
+//! requiring a nightly compiler, lets collections hold references
+//! that are dropped at the same time as them, like the ones in `std`.
+//! The `unstable` feature exposes APIs tied to implementation details,
+//! like the nodes of `LinkedList`, which may change in minor versions.
 
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+#[cfg(feature = "alloc")]
+mod allocator;
+#[cfg(feature = "alloc")]
+pub mod doubly_linked_list;
+pub mod errors;
+#[cfg(all(feature = "alloc", feature = "defmt"))]
+mod format;
+pub mod hash;
+#[cfg(feature = "alloc")]
 pub mod linked_list;
+#[cfg(feature = "alloc")]
+mod macros;
+pub mod prelude;
+pub mod sorted_iter;
+#[cfg(all(test, feature = "alloc"))]
+mod test_utils;
+#[cfg(feature = "alloc")]
+pub mod vec_deque;
+
+#[cfg(all(feature = "alloc", feature = "defmt"))]
+pub use crate::format::DEFMT_MAX_ELEMENTS;
@@ -1,28 +1,631 @@
 //! Implementations of linked lists with owned nodes.
 
 use core::{
+    alloc::Layout,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
     ptr::NonNull,
     marker::PhantomData,
-    ops::{Index, IndexMut},
+    iter::{self, FromIterator, FusedIterator},
+    mem,
+    ops::{Bound, Index, IndexMut, RangeBounds},
+    ptr,
 };
-use alloc::boxed::Box;
+use alloc::{alloc::handle_alloc_error, boxed::Box, vec::Vec};
+use crate::{
+    allocator::{Allocator, Global},
+    errors::{AllocError, InsertError},
+    macros::impl_slice_eq,
+};
+
+/// Creates a [`LinkedList`] containing the arguments.
+///
+/// Like `vec!`, `linked_list!` accepts either a list of elements
+/// or an element and a number of repetitions, the element being cloned.
+///
+/// # Examples
+///
+/// ```
+/// use collections::linked_list;
+///
+/// let list = linked_list![1, 2, 3];
+/// assert_eq!(list.len(), 3);
+/// assert_eq!(list.back(), Some(&3));
+///
+/// let list = linked_list![0; 4];
+/// assert_eq!(list.len(), 4);
+/// assert_eq!(list.front(), Some(&0));
+/// ```
+#[macro_export]
+macro_rules! linked_list {
+    () => {
+        $crate::linked_list::LinkedList::new()
+    };
+    ($element:expr; $n:expr) => {{
+        let element = $element;
+        let mut list = $crate::linked_list::LinkedList::new();
+        for _ in 0..$n {
+            list.push_back(::core::clone::Clone::clone(&element));
+        }
+        list
+    }};
+    ($($x:expr),+ $(,)?) => {{
+        let mut list = $crate::linked_list::LinkedList::new();
+        $(list.push_back($x);)+
+        list
+    }};
+}
 
 /// A singly-linked list with owned nodes.
 ///
 /// The `LinkedList` allows pushing at either end and popping
-/// at the front elements in constant time.
-pub struct LinkedList<T> {
+/// at the front elements in constant time. Its nodes only link to the
+/// next one, making them lighter than the ones of a [`DoublyLinkedList`],
+/// which can also pop at the back and iterate in reverse.
+///
+/// The nodes are allocated with `A`, the global allocator by default.
+/// Other allocators can be used with the `allocator_api` feature.
+///
+/// A list created with [`with_pool`](LinkedList::with_pool) keeps the
+/// nodes of removed elements to reuse them, avoiding allocator calls.
+///
+/// [`DoublyLinkedList`]: crate::doubly_linked_list::DoublyLinkedList
+pub struct LinkedList<T, A: Allocator = Global> {
     head: Option<NonNull<Node<T>>>,
     tail: Option<NonNull<Node<T>>>,
     len: usize,
+    alloc: A,
+
+    /// Chain of free nodes, whose elements are uninitialized.
+    pool: Option<NonNull<Node<T>>>,
+    pool_len: usize,
+    pool_capacity: usize,
 
     /// Indicates that `LinkedList` owns some `Box<Node>`
     marker: PhantomData<Box<Node<T>>>,
 }
 
-struct Node<T> {
-    element: T,
-    next: Option<NonNull<Node<T>>>,
+mod node {
+    use core::ptr::NonNull;
+
+    /// A node of a `LinkedList`, holding one element.
+    ///
+    /// Nodes can be moved between lists without moving their element with
+    /// [`LinkedList::pop_front_node`] and [`LinkedList::push_back_node`].
+    ///
+    /// [`LinkedList::pop_front_node`]: super::LinkedList::pop_front_node
+    /// [`LinkedList::push_back_node`]: super::LinkedList::push_back_node
+    // `repr(C)` so that `Node<T>` and `Node<U>` have the same layout
+    // when `T` and `U` do, see `LinkedList::map`.
+    #[repr(C)]
+    pub struct Node<T> {
+        pub(super) element: T,
+        pub(super) next: Option<NonNull<Node<T>>>,
+    }
+}
+
+#[cfg(feature = "unstable")]
+pub use self::node::Node;
+#[cfg(not(feature = "unstable"))]
+use self::node::Node;
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+/// Nodes linked together but owned by no list, so that they can be
+/// relinked without an allocator. Dropping a `Chain` leaks its nodes.
+struct Chain<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+}
+
+/// An iterator over the elements of a `LinkedList`.
+///
+/// This `struct` is created by [`LinkedList::iter`].
+pub struct Iter<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    /// Number of elements left, ending the iteration even if `head`
+    /// isn't `None`, so that a back end can be added.
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+/// A mutable iterator over the elements of a `LinkedList`.
+///
+/// This `struct` is created by [`LinkedList::iter_mut`].
+pub struct IterMut<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    /// Number of elements left, see [`Iter`].
+    len: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+/// An owning iterator over the elements of a `LinkedList`.
+///
+/// This `struct` is created by the [`into_iter`] method on [`LinkedList`]
+/// (provided by the `IntoIterator` trait).
+///
+/// [`into_iter`]: LinkedList::into_iter
+pub struct IntoIter<T, A: Allocator = Global> {
+    list: LinkedList<T, A>,
+}
+
+/// A cursor over a `LinkedList`.
+///
+/// A `Cursor` is like an iterator, except that it can freely seek
+/// forward and keeps track of its position.
+///
+/// Cursors always rest between two elements in the list, and index in a
+/// logically circular way. To accommodate this, there is a "ghost"
+/// non-element that yields `None` between the tail and head of the list.
+///
+/// This `struct` is created by [`LinkedList::cursor_front`].
+pub struct Cursor<'a, T, A: Allocator = Global> {
+    index: usize,
+    current: Option<NonNull<Node<T>>>,
+    list: &'a LinkedList<T, A>,
+}
+
+/// A cursor over a `LinkedList` with editing operations.
+///
+/// A `CursorMut` is like an iterator, except that it can freely seek
+/// forward, and can safely mutate the list during iteration. This is
+/// because the lifetime of its yielded references is tied to its own
+/// lifetime, instead of just the underlying list.
+///
+/// Cursors always rest between two elements in the list, and index in a
+/// logically circular way. To accommodate this, there is a "ghost"
+/// non-element that yields `None` between the tail and head of the list.
+///
+/// This `struct` is created by [`LinkedList::cursor_front_mut`].
+pub struct CursorMut<'a, T, A: Allocator = Global> {
+    index: usize,
+    current: Option<NonNull<Node<T>>>,
+    /// The node before `current`, or the tail if `current` is the ghost.
+    prev: Option<NonNull<Node<T>>>,
+    list: &'a mut LinkedList<T, A>,
+}
+
+/// A handle to an element of a `LinkedList`.
+///
+/// It stays valid as long as its element is in the list, whatever
+/// happens to the other elements, allowing to access it without
+/// searching the list. Removing it still walks the list, see
+/// [`LinkedList::remove_handle`].
+///
+/// This `struct` is created by [`LinkedList::push_front_handle`] and
+/// [`LinkedList::push_back_handle`].
+pub struct NodeRef<T> {
+    node: NonNull<Node<T>>,
+}
+
+/// An iterator removing the elements of a `LinkedList` matching a predicate.
+///
+/// This `struct` is created by [`LinkedList::extract_if`].
+pub struct ExtractIf<'a, T, F, A: Allocator = Global>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    list: &'a mut LinkedList<T, A>,
+    prev: Option<NonNull<Node<T>>>,
+    node: Option<NonNull<Node<T>>>,
+    /// Number of nodes not yet visited.
+    remaining: usize,
+    pred: F,
+}
+
+/// A draining iterator over a range of elements of a `LinkedList`.
+///
+/// The elements are unlinked from the list as soon as the `Drain` is
+/// created, and the ones not yielded are dropped with it.
+///
+/// This `struct` is created by [`LinkedList::drain`].
+pub struct Drain<'a, T, A: Allocator = Global> {
+    list: &'a mut LinkedList<T, A>,
+    head: Option<NonNull<Node<T>>>,
+    len: usize,
+}
+
+/// A splicing iterator for `LinkedList`.
+///
+/// This `struct` is created by [`LinkedList::splice`].
+pub struct Splice<'a, I: Iterator, A: Allocator = Global> {
+    drain: Drain<'a, I::Item, A>,
+    /// The node after which `replace_with` is linked,
+    /// `None` for the front of the list.
+    before: Option<NonNull<Node<I::Item>>>,
+    replace_with: I,
+}
+
+#[cfg(feature = "unstable")]
+impl<T> Node<T> {
+    /// Creates a node holding `element`, to be pushed with
+    /// [`LinkedList::push_front_node`] or [`LinkedList::push_back_node`].
+    #[inline]
+    pub fn new(element: T) -> Self {
+        Node {
+            element,
+            next: None,
+        }
+    }
+
+    /// Returns a reference to the element.
+    #[inline]
+    pub fn element(&self) -> &T {
+        &self.element
+    }
+
+    /// Returns a mutable reference to the element.
+    #[inline]
+    pub fn element_mut(&mut self) -> &mut T {
+        &mut self.element
+    }
+
+    /// Consumes the node, returning the element.
+    #[inline]
+    pub fn into_element(self) -> T {
+        self.element
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl<T: fmt::Debug> fmt::Debug for Node<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Node").field(&self.element).finish()
+    }
+}
+
+// Private methods
+impl<T, A: Allocator> LinkedList<T, A> {
+    /// Frees the pool and every node of the list, dropping the elements.
+    fn drop_nodes(&mut self) {
+        self.pool_capacity = 0;
+        self.shrink_pool();
+        unsafe { self.free_nodes(self.head) };
+    }
+
+    /// Allocates a node holding `element` with the list's allocator.
+    fn alloc_node(&mut self, element: T) -> NonNull<Node<T>> {
+        match self.try_alloc_node(element) {
+            Ok(node) => node,
+            Err(_) => handle_alloc_error(Layout::new::<Node<T>>()),
+        }
+    }
+
+    /// Takes a node from the pool or allocates one with the list's
+    /// allocator, and moves `element` in it.
+    ///
+    /// `element` is dropped if the allocation fails.
+    fn try_alloc_node(&mut self, element: T) -> Result<NonNull<Node<T>>, AllocError> {
+        let node = match self.pool {
+            Some(node) => {
+                self.pool = unsafe { (*node.as_ptr()).next };
+                self.pool_len -= 1;
+                node
+            }
+            None => {
+                let layout = Layout::new::<Node<T>>();
+                self.alloc.allocate(layout).map_err(|_| AllocError)?.cast::<Node<T>>()
+            }
+        };
+        unsafe { node.as_ptr().write(Node { element, next: None }); }
+        Ok(node)
+    }
+
+    /// Moves `node` to the pool if it isn't full, deallocates it otherwise,
+    /// and returns its element.
+    ///
+    /// # Safety
+    ///
+    /// `node` must have been allocated by the list's allocator,
+    /// and must not be linked anymore.
+    unsafe fn free_node(&mut self, node: NonNull<Node<T>>) -> T {
+        let element = ptr::read(&(*node.as_ptr()).element);
+        if self.pool_len < self.pool_capacity {
+            (*node.as_ptr()).next = self.pool;
+            self.pool = Some(node);
+            self.pool_len += 1;
+        } else {
+            self.alloc.deallocate(node.cast(), Layout::new::<Node<T>>());
+        }
+        element
+    }
+
+    /// Drops the elements and deallocates the nodes from `node`
+    /// to the end of its chain.
+    ///
+    /// If dropping an element panics, the remaining nodes are leaked.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`free_node`](Self::free_node), for every node of the chain.
+    unsafe fn free_nodes(&mut self, mut node: Option<NonNull<Node<T>>>) {
+        while let Some(n) = node {
+            node = (*n.as_ptr()).next;
+            drop(self.free_node(n));
+        }
+    }
+
+    /// Adds the given node to the front of the list.
+    fn link_front_node(&mut self, node: NonNull<Node<T>>) {
+        unsafe { (*node.as_ptr()).next = self.head; }
+        let node = Some(node);
+
+        if self.tail.is_none() {
+            self.tail = node;
+        }
+
+        self.head = node;
+        self.len += 1;
+    }
+
+    /// Adds the given node to the back of the list.
+    fn link_back_node(&mut self, node: NonNull<Node<T>>) {
+        unsafe { (*node.as_ptr()).next = None; }
+        let node = Some(node);
+
+        if let Some(tail) = self.tail {
+            unsafe { (*tail.as_ptr()).next = node; }
+        } else {
+            self.head = node;
+        }
+
+        self.tail = node;
+        self.len += 1;
+    }
+
+    /// Unlinks and returns the node at the front of the list.
+    fn unlink_front_node(&mut self) -> Option<NonNull<Node<T>>> {
+        let node = self.head?;
+        self.head = unsafe { node.as_ref() }.next;
+
+        if self.head.is_none() {
+            self.tail = None;
+        }
+
+        self.len -= 1;
+        Some(node)
+    }
+
+    /// Unlinks `node` from the list and returns it.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be in the list, and `prev` must be the node before it,
+    /// or `None` if `node` is the head.
+    unsafe fn unlink_node(
+        &mut self,
+        prev: Option<NonNull<Node<T>>>,
+        node: NonNull<Node<T>>,
+    ) -> NonNull<Node<T>> {
+        let next = (*node.as_ptr()).next;
+        match prev {
+            Some(prev) => (*prev.as_ptr()).next = next,
+            None => self.head = next,
+        }
+        if next.is_none() {
+            self.tail = prev;
+        }
+
+        self.len -= 1;
+        node
+    }
+
+    /// Links `node` after `prev`, or at the front if `prev` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `prev` must be in the list.
+    unsafe fn link_after(&mut self, prev: Option<NonNull<Node<T>>>, node: NonNull<Node<T>>) {
+        let next = match prev {
+            Some(prev) => (*prev.as_ptr()).next.replace(node),
+            None => self.head.replace(node),
+        };
+        (*node.as_ptr()).next = next;
+        if next.is_none() {
+            self.tail = Some(node);
+        }
+        self.len += 1;
+    }
+
+    /// Unlinks the elements in `range`, returning the node before them,
+    /// the chain they form and its length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the list.
+    fn unlink_range<R>(&mut self, range: R) -> (Link<T>, Link<T>, usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start
+                .checked_add(1)
+                .expect("attempted to index from after the maximum usize"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end
+                .checked_add(1)
+                .expect("attempted to index up to the maximum usize"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+        assert!(
+            start <= end,
+            "range start (is {}) should be <= end (is {})",
+            start,
+            end,
+        );
+        assert!(
+            end <= self.len,
+            "range end (is {}) should be <= len (is {})",
+            end,
+            self.len,
+        );
+
+        let mut before = None;
+        let mut first = self.head;
+        for _ in 0..start {
+            before = first;
+            first = unsafe { first.unwrap().as_ref() }.next;
+        }
+
+        let len = end - start;
+        if len == 0 {
+            return (before, None, 0);
+        }
+
+        let mut last = first.unwrap();
+        for _ in 1..len {
+            last = unsafe { last.as_ref() }.next.unwrap();
+        }
+
+        let after = unsafe { (*last.as_ptr()).next.take() };
+        match before {
+            Some(before) => unsafe { (*before.as_ptr()).next = after; },
+            None => self.head = after,
+        }
+        if after.is_none() {
+            self.tail = before;
+        }
+        self.len -= len;
+
+        (before, first, len)
+    }
+
+    /// Appends the elements of `iter`, linking each new node
+    /// directly after the previous one.
+    fn push_back_iter<I: Iterator<Item = T>>(&mut self, mut iter: I) {
+        let mut tail = match self.tail {
+            Some(tail) => tail,
+            None => match iter.next() {
+                Some(element) => {
+                    self.push_back(element);
+                    self.tail.unwrap()
+                }
+                None => return,
+            },
+        };
+
+        for element in iter {
+            let node = self.alloc_node(element);
+
+            unsafe { (*tail.as_ptr()).next = Some(node); }
+            tail = node;
+            self.tail = Some(node);
+            self.len += 1;
+        }
+    }
+
+    /// Moves all the nodes of the list to a `Chain`.
+    fn take_chain(&mut self) -> Chain<T> {
+        Chain {
+            head: self.head.take(),
+            tail: self.tail.take(),
+            len: mem::replace(&mut self.len, 0),
+        }
+    }
+
+    /// Moves all the nodes of `chain` to the back of the list.
+    fn append_chain(&mut self, chain: &mut Chain<T>) {
+        let chain = mem::replace(chain, Chain::EMPTY);
+        if let Some(head) = chain.head {
+            match self.tail {
+                Some(tail) => unsafe { (*tail.as_ptr()).next = Some(head); },
+                None => self.head = Some(head),
+            }
+            self.tail = chain.tail;
+            self.len += chain.len;
+        }
+    }
+}
+
+impl<T> Chain<T> {
+    const EMPTY: Self = Chain {
+        head: None,
+        tail: None,
+        len: 0,
+    };
+
+    /// Unlinks and returns the node at the front of the chain.
+    fn pop_front(&mut self) -> Link<T> {
+        let node = self.head?;
+        self.head = unsafe { node.as_ref() }.next;
+        if self.head.is_none() {
+            self.tail = None;
+        }
+        self.len -= 1;
+        Some(node)
+    }
+
+    /// Adds `node` to the back of the chain.
+    fn push_back(&mut self, node: NonNull<Node<T>>) {
+        unsafe { (*node.as_ptr()).next = None; }
+        match self.tail {
+            Some(tail) => unsafe { (*tail.as_ptr()).next = Some(node); },
+            None => self.head = Some(node),
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    /// Moves all the nodes of `other` to the back of the chain.
+    fn append(&mut self, other: &mut Self) {
+        let other = mem::replace(other, Self::EMPTY);
+        match self.tail {
+            Some(tail) => unsafe { (*tail.as_ptr()).next = other.head; },
+            None => self.head = other.head,
+        }
+        if other.tail.is_some() {
+            self.tail = other.tail;
+        }
+        self.len += other.len;
+    }
+
+    /// Merges `other` into `self`, both being sorted according to `compare`.
+    ///
+    /// Equal elements of `self` are kept before the ones of `other`.
+    /// If `compare` panics, all the nodes end up in `self`
+    /// in an unspecified order.
+    fn merge_sorted_by<F>(&mut self, other: &mut Self, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        struct Guard<'a, T> {
+            merged: Chain<T>,
+            a: &'a mut Chain<T>,
+            b: &'a mut Chain<T>,
+        }
+
+        impl<T> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                self.merged.append(self.a);
+                self.merged.append(self.b);
+                mem::swap(self.a, &mut self.merged);
+            }
+        }
+
+        let mut guard = Guard {
+            merged: Chain::EMPTY,
+            a: self,
+            b: other,
+        };
+
+        while let (Some(a), Some(b)) = (guard.a.head, guard.b.head) {
+            let node = unsafe {
+                if compare(&(*b.as_ptr()).element, &(*a.as_ptr()).element) == Ordering::Less {
+                    guard.b.pop_front()
+                } else {
+                    guard.a.pop_front()
+                }
+            };
+            guard.merged.push_back(node.unwrap());
+        }
+    }
 }
 
 impl<T> LinkedList<T> {
@@ -37,62 +640,78 @@ impl<T> LinkedList<T> {
     /// ```
     #[inline]
     pub fn new() -> Self {
-        Self {
-            head: None,
-            tail: None,
-            len: 0,
-            marker: PhantomData,
-        }
+        Self::new_in(Global)
     }
 
-    /// Returns the length of the `LinkedList°.
+    /// Creates an empty `LinkedList` keeping up to `capacity` nodes of
+    /// removed elements for reuse.
     ///
-    /// This operation should compute in *O*(1) time.
+    /// Once the pool is filled, pushing and removing elements don't call
+    /// the allocator as long as the list doesn't grow bigger. The pool
+    /// stays with the list: lists created from it, e.g. by
+    /// [`split_off`](LinkedList::split_off), don't have one.
     ///
     /// # Examples
     ///
     /// ```
     /// use collections::linked_list::LinkedList;
     ///
-    /// let mut list = LinkedList::new();
+    /// let mut list = LinkedList::with_pool(1);
+    /// for i in 0..100 {
+    ///     // Only allocates the first time.
+    ///     list.push_back(i);
+    ///     assert_eq!(list.pop_front(), Some(i));
+    /// }
+    /// assert_eq!(list.pool_len(), 1);
+    /// ```
+    #[inline]
+    pub fn with_pool(capacity: usize) -> Self {
+        Self::with_pool_in(capacity, Global)
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl<T> LinkedList<T> {
+    /// Adds a node to the front of the list, without moving its element.
     ///
-    /// list.push_front(2);
-    /// assert_eq!(list.len(), 1);
+    /// This operation should compute in *O*(1) time.
     ///
-    /// list.push_front(1);
-    /// assert_eq!(list.len(), 2);
+    /// # Examples
     ///
-    /// list.push_back(3);
-    /// assert_eq!(list.len(), 3);
     /// ```
-    #[inline]
-    pub fn len(&self) -> usize {
-        self.len
+    /// use collections::linked_list::{LinkedList, Node};
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push_front_node(Box::new(Node::new(2)));
+    /// list.push_front_node(Box::new(Node::new(1)));
+    /// assert_eq!(list, [1, 2]);
+    /// ```
+    pub fn push_front_node(&mut self, node: Box<Node<T>>) {
+        self.link_front_node(Box::leak(node).into());
     }
 
-    /// Provides a reference to the front element,
-    /// or `None` if the list is empty.
+    /// Adds a node to the back of the list, without moving its element.
     ///
     /// This operation should compute in *O*(1) time.
     ///
     /// # Examples
     ///
     /// ```
-    /// use collections::linked_list::LinkedList;
+    /// use collections::linked_list::{LinkedList, Node};
     ///
     /// let mut list = LinkedList::new();
-    /// assert_eq!(list.front(), None);
-    ///
-    /// list.push_front(1);
-    /// assert_eq!(list.front(), Some(&1));
+    /// list.push_back_node(Box::new(Node::new(1)));
+    /// list.push_back_node(Box::new(Node::new(2)));
+    /// assert_eq!(list, [1, 2]);
     /// ```
-    #[inline]
-    pub fn front(&self) -> Option<&T> {
-        self.head.as_ref().map(|node| &unsafe { node.as_ref() }.element)
+    pub fn push_back_node(&mut self, node: Box<Node<T>>) {
+        self.link_back_node(Box::leak(node).into());
     }
 
-    /// Provides a mutable reference to the front element,
-    /// or `None` if the list is empty.
+    /// Removes the first node and returns it, or `None` if the list is
+    /// empty. The element stays in place, in the node.
+    ///
+    /// The node doesn't go to the pool of the list.
     ///
     /// This operation should compute in *O*(1) time.
     ///
@@ -101,27 +720,322 @@ impl<T> LinkedList<T> {
     /// ```
     /// use collections::linked_list::LinkedList;
     ///
-    /// let mut list = LinkedList::new();
-    /// assert_eq!(list.front(), None);
-    ///
-    /// list.push_front(1);
-    /// assert_eq!(list.front(), Some(&1));
+    /// let mut ready: LinkedList<_> = ["a", "b"].into();
+    /// let mut waiting = LinkedList::new();
     ///
-    /// match list.front_mut() {
-    ///     None => {},
-    ///     Some(x) => *x = 5,
+    /// while let Some(node) = ready.pop_front_node() {
+    ///     waiting.push_back_node(node);
     /// }
-    /// assert_eq!(list.front(), Some(&5));
+    /// assert!(ready.is_empty());
+    /// assert_eq!(waiting, ["a", "b"]);
     /// ```
-    #[inline]
-    pub fn front_mut(&mut self) -> Option<&mut T> {
-        self.head.as_mut().map(|node| &mut unsafe { node.as_mut() }.element)
+    pub fn pop_front_node(&mut self) -> Option<Box<Node<T>>> {
+        let node = self.unlink_front_node()?;
+        Some(unsafe { Box::from_raw(node.as_ptr()) })
     }
+}
 
-    /// Provides a reference to the back element,
-    /// or `None` if the list is empty.
+impl<T, A: Allocator> LinkedList<T, A> {
+    /// Creates an empty `LinkedList` allocating its nodes with `alloc`.
     ///
-    /// This operation should compute in *O*(1) time.
+    /// Allocators other than the global one can only be used with the
+    /// `allocator_api` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u32> = LinkedList::new();
+    /// let mut other = LinkedList::new_in(list.allocator().clone());
+    /// other.push_back(1);
+    /// assert_eq!(other, [1]);
+    /// ```
+    #[inline]
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            alloc,
+            pool: None,
+            pool_len: 0,
+            pool_capacity: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates an empty `LinkedList` keeping up to `capacity` nodes of
+    /// removed elements for reuse, allocating its nodes with `alloc`.
+    ///
+    /// See [`with_pool`](LinkedList::with_pool).
+    #[inline]
+    pub fn with_pool_in(capacity: usize, alloc: A) -> Self {
+        let mut list = Self::new_in(alloc);
+        list.pool_capacity = capacity;
+        list
+    }
+
+    /// Returns the number of nodes kept for reuse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::with_pool(2);
+    /// list.extend([1, 2, 3]);
+    /// assert_eq!(list.pool_len(), 0);
+    ///
+    /// list.clear();
+    /// assert_eq!(list.pool_len(), 2);
+    /// ```
+    #[inline]
+    pub fn pool_len(&self) -> usize {
+        self.pool_len
+    }
+
+    /// Deallocates the nodes kept for reuse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::with_pool(8);
+    /// list.push_back(1);
+    /// list.pop_front();
+    /// assert_eq!(list.pool_len(), 1);
+    ///
+    /// list.shrink_pool();
+    /// assert_eq!(list.pool_len(), 0);
+    /// ```
+    pub fn shrink_pool(&mut self) {
+        while let Some(node) = self.pool {
+            unsafe {
+                self.pool = (*node.as_ptr()).next;
+                self.alloc.deallocate(node.cast(), Layout::new::<Node<T>>());
+            }
+        }
+        self.pool_len = 0;
+    }
+
+    /// Returns a reference to the underlying allocator.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Returns the length of the `LinkedList°.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_front(2);
+    /// assert_eq!(list.len(), 1);
+    ///
+    /// list.push_front(1);
+    /// assert_eq!(list.len(), 2);
+    ///
+    /// list.push_back(3);
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the `LinkedList` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// assert!(list.is_empty());
+    ///
+    /// list.push_front(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Provides a forward iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(0);
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&0));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Provides a forward iterator with mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(0);
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// for element in list.iter_mut() {
+    ///     *element += 10;
+    /// }
+    ///
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&10));
+    /// assert_eq!(iter.next(), Some(&11));
+    /// assert_eq!(iter.next(), Some(&12));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Provides a cursor at the front element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u32> = (1..4).collect();
+    ///
+    /// let mut cursor = list.cursor_front();
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.index(), Some(1));
+    /// assert_eq!(cursor.peek_next(), Some(&3));
+    /// ```
+    #[inline]
+    pub fn cursor_front(&self) -> Cursor<'_, T, A> {
+        Cursor {
+            index: 0,
+            current: self.head,
+            list: self,
+        }
+    }
+
+    /// Provides a cursor with editing operations at the front element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = (1..4).collect();
+    ///
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// cursor.insert_after(5);
+    /// assert_eq!(list, [1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, A> {
+        CursorMut {
+            index: 0,
+            current: self.head,
+            prev: None,
+            list: self,
+        }
+    }
+
+    /// Provides a reference to the front element,
+    /// or `None` if the list is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.front(), None);
+    ///
+    /// list.push_front(1);
+    /// assert_eq!(list.front(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &unsafe { node.as_ref() }.element)
+    }
+
+    /// Provides a mutable reference to the front element,
+    /// or `None` if the list is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.front(), None);
+    ///
+    /// list.push_front(1);
+    /// assert_eq!(list.front(), Some(&1));
+    ///
+    /// match list.front_mut() {
+    ///     None => {},
+    ///     Some(x) => *x = 5,
+    /// }
+    /// assert_eq!(list.front(), Some(&5));
+    /// ```
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut unsafe { node.as_mut() }.element)
+    }
+
+    /// Provides a reference to the back element,
+    /// or `None` if the list is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
     ///
     /// # Examples
     ///
@@ -221,6 +1135,58 @@ impl<T> LinkedList<T> {
         node.as_ref().map(|node| unsafe { &mut (*node.as_ptr()).element })
     }
 
+    /// Swaps the elements at positions `i` and `j`.
+    ///
+    /// The elements are swapped in place, the nodes stay linked the same way.
+    /// `i` and `j` may be equal.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<char> = "abcd".chars().collect();
+    ///
+    /// list.swap(1, 3);
+    /// assert_eq!(list, ['a', 'd', 'c', 'b']);
+    /// ```
+    pub fn swap(&mut self, i: usize, j: usize) {
+        let max = i.max(j);
+        assert!(
+            max < self.len,
+            "swap index (is {}) should be < len (is {})",
+            max,
+            self.len,
+        );
+
+        let mut a = self.head.unwrap();
+        let mut b = a;
+        let mut node = a;
+        for index in 0..=max {
+            if index == i {
+                a = node;
+            }
+            if index == j {
+                b = node;
+            }
+            if index < max {
+                node = unsafe { node.as_ref() }.next.unwrap();
+            }
+        }
+
+        if a != b {
+            unsafe {
+                ptr::swap(&mut (*a.as_ptr()).element, &mut (*b.as_ptr()).element);
+            }
+        }
+    }
+
     /// Adds an element first in the list.
     ///
     /// This operation should compute in *O*(1) time.
@@ -239,18 +1205,8 @@ impl<T> LinkedList<T> {
     /// assert_eq!(list.front().unwrap(), &1);
     /// ```
     pub fn push_front(&mut self, element: T) {
-        let node = Box::new(Node {
-            element,
-            next: self.head,
-        });
-        let node = Some(Box::leak(node).into());
-
-        if self.tail.is_none() {
-            self.tail = node;
-        }
-
-        self.head = node;
-        self.len += 1;
+        let node = self.alloc_node(element);
+        self.link_front_node(node);
     }
 
     /// Appends an element to the back of a list.
@@ -268,24 +1224,14 @@ impl<T> LinkedList<T> {
     /// assert_eq!(3, *list.back().unwrap());
     /// ```
     pub fn push_back(&mut self, element: T) {
-        let node = Box::new(Node {
-            element,
-            next: None,
-        });
-        let node = Some(Box::leak(node).into());
-
-        if let Some(tail) = self.tail {
-            unsafe { (*tail.as_ptr()).next = node; }
-        } else {
-            self.head = node;
-        }
-
-        self.tail = node;
-        self.len += 1;
+        let node = self.alloc_node(element);
+        self.link_back_node(node);
     }
 
-    /// Removes the first element and returns it,
-    /// or `None` if the list is empty.
+    /// Adds an element first in the list, returning an error instead of
+    /// aborting if the allocator fails.
+    ///
+    /// On error, the list is left unchanged and `element` is dropped.
     ///
     /// This operation should compute in *O*(1) time.
     ///
@@ -295,32 +1241,176 @@ impl<T> LinkedList<T> {
     /// use collections::linked_list::LinkedList;
     ///
     /// let mut list = LinkedList::new();
-    /// assert_eq!(list.pop_front(), None);
     ///
-    /// list.push_front(1);
-    /// list.push_front(3);
-    /// assert_eq!(list.pop_front(), Some(3));
-    /// assert_eq!(list.pop_front(), Some(1));
-    /// assert_eq!(list.pop_front(), None);
+    /// list.try_push_front(2).expect("out of memory");
+    /// list.try_push_front(1).expect("out of memory");
+    /// assert_eq!(list, [1, 2]);
     /// ```
-    pub fn pop_front(&mut self) -> Option<T> {
-        self.head.map(|node| {
-            let node = unsafe { Box::from_raw(node.as_ptr()) };
-            self.head = node.next;
+    pub fn try_push_front(&mut self, element: T) -> Result<(), AllocError> {
+        let node = self.try_alloc_node(element)?;
+        self.link_front_node(node);
+        Ok(())
+    }
 
-            if self.head.is_none() {
-                self.tail = None;
-            }
+    /// Appends an element to the back of a list, returning an error
+    /// instead of aborting if the allocator fails.
+    ///
+    /// On error, the list is left unchanged and `element` is dropped.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.try_push_back(1).expect("out of memory");
+    /// list.try_push_back(2).expect("out of memory");
+    /// assert_eq!(list, [1, 2]);
+    /// ```
+    pub fn try_push_back(&mut self, element: T) -> Result<(), AllocError> {
+        let node = self.try_alloc_node(element)?;
+        self.link_back_node(node);
+        Ok(())
+    }
 
-            self.len -= 1;
-            node.element
-        })
+    /// Adds an element first in the list and returns a handle to it.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// let handle = list.push_front_handle(1);
+    /// list.push_front(0);
+    /// assert_eq!(unsafe { list.get_handle(handle) }, &1);
+    /// ```
+    pub fn push_front_handle(&mut self, element: T) -> NodeRef<T> {
+        let node = self.alloc_node(element);
+        self.link_front_node(node);
+        NodeRef { node }
+    }
+
+    /// Appends an element to the back of the list and returns a handle
+    /// to it.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// let handle = list.push_back_handle(1);
+    /// list.push_back(2);
+    /// assert_eq!(unsafe { list.get_handle(handle) }, &1);
+    /// ```
+    pub fn push_back_handle(&mut self, element: T) -> NodeRef<T> {
+        let node = self.alloc_node(element);
+        self.link_back_node(node);
+        NodeRef { node }
+    }
+
+    /// Provides a reference to the element of `handle`.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must have been returned by this list, and its element must
+    /// not have been removed since.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = (0..3).collect();
+    ///
+    /// let handle = list.push_back_handle(3);
+    /// list.pop_front();
+    /// assert_eq!(unsafe { list.get_handle(handle) }, &3);
+    /// ```
+    #[inline]
+    pub unsafe fn get_handle(&self, handle: NodeRef<T>) -> &T {
+        &(*handle.node.as_ptr()).element
+    }
+
+    /// Removes the element of `handle` and returns it.
+    ///
+    /// This is a linear-time convenience: it should compute in *O*(*n*)
+    /// time, as the node before the element has to be found. A
+    /// [`DoublyLinkedList`](crate::doubly_linked_list::DoublyLinkedList)
+    /// removes its handles in constant time.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must have been returned by this list, and its element must
+    /// not have been removed since.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(1);
+    /// let handle = list.push_back_handle(2);
+    /// list.push_back(3);
+    ///
+    /// assert_eq!(unsafe { list.remove_handle(handle) }, 2);
+    /// assert_eq!(list, [1, 3]);
+    /// ```
+    pub unsafe fn remove_handle(&mut self, handle: NodeRef<T>) -> T {
+        let mut prev = None;
+        let mut node = self.head.expect("handle should be in the list");
+        while node != handle.node {
+            prev = Some(node);
+            node = node.as_ref().next.expect("handle should be in the list");
+        }
+
+        let node = self.unlink_node(prev, node);
+        self.free_node(node)
+    }
+
+    /// Removes the first element and returns it,
+    /// or `None` if the list is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.pop_front(), None);
+    ///
+    /// list.push_front(1);
+    /// list.push_front(3);
+    /// assert_eq!(list.pop_front(), Some(3));
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.unlink_front_node().map(|node| unsafe { self.free_node(node) })
     }
 
     /// Removes the last element from a list and returns it,
     /// or `None` if it is empty.
     ///
-    /// This operation should compute in *O*(*n*) time.
+    /// This operation should compute in *O*(*n*) time. A
+    /// [`DoublyLinkedList`](crate::doubly_linked_list::DoublyLinkedList)
+    /// pops at the back in constant time.
     ///
     /// # Examples
     ///
@@ -335,8 +1425,6 @@ impl<T> LinkedList<T> {
     /// ```
     pub fn pop_back(&mut self) -> Option<T> {
         self.tail.map(|node| {
-            let node = unsafe { Box::from_raw(node.as_ptr()) };
-
             if let Some(penultimate) = {
                 let mut last = self.head.unwrap();
                 let mut penultimate = None;
@@ -354,7 +1442,7 @@ impl<T> LinkedList<T> {
             }
 
             self.len -= 1;
-            node.element
+            unsafe { self.free_node(node) }
         })
     }
 
@@ -380,88 +1468,97 @@ impl<T> LinkedList<T> {
     /// ```
     #[inline]
     pub fn clear(&mut self) {
-        *self = Self::new();
+        self.truncate(0);
     }
 
-    /// Insert an element at position `index` within the LinkedList.
-    ///
-    /// This operation should compute in *O*(*n*) time.
+    /// Shortens the list, keeping the first `len` elements and dropping
+    /// the rest.
     ///
-    /// # Panics
+    /// If `len` is greater or equal to the list's current length,
+    /// this has no effect.
     ///
-    /// Panics if `index` > `len`.
+    /// This operation should compute in *O*(*n*) time.
     ///
     /// # Examples
     ///
     /// ```
     /// use collections::linked_list::LinkedList;
     ///
-    /// let mut list = LinkedList::new();
-    ///
-    /// list.insert(0, 4);
-    /// assert_eq!(list.len(), 1);
-    /// assert_eq!(list.front(), Some(&4));
+    /// let mut list: LinkedList<u32> = (1..6).collect();
     ///
-    /// list.insert(1, 2);
-    /// list.insert(1, 7);
-    /// assert_eq!(list.len(), 3);
-    /// assert_eq!(list.front(), Some(&4));
+    /// list.truncate(2);
+    /// assert_eq!(list, [1, 2]);
     /// assert_eq!(list.back(), Some(&2));
-    /// ```
-    ///
-    /// ```should_panic
-    /// use collections::linked_list::LinkedList;
-    ///
-    /// let mut list = LinkedList::new();
     ///
-    /// list.insert(5, 6);
+    /// list.truncate(8);
+    /// assert_eq!(list, [1, 2]);
     /// ```
-    pub fn insert(&mut self, index: usize, element: T) {
-        let mut before = None;
-        let mut after = self.head;
-        for _ in 0..index {
-            before = after;
-            after = unsafe { after.unwrap().as_ref() }.next;
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
         }
 
-        let node = Box::new(Node {
-            element,
-            next: after,
-        });
-        let node = Some(Box::leak(node).into());
-
-        if let Some(b) = before {
-            unsafe { (*b.as_ptr()).next = node; }
+        let rest = if len == 0 {
+            self.tail = None;
+            self.head.take()
         } else {
-            self.head = node;
-        }
-        if after.is_none() {
-            self.tail = node;
-        }
+            let mut last = self.head.unwrap();
+            for _ in 1..len {
+                last = unsafe { last.as_ref() }.next.unwrap();
+            }
+            self.tail = Some(last);
+            unsafe { (*last.as_ptr()).next.take() }
+        };
+        self.len = len;
 
-        self.len += 1;
+        unsafe { self.free_nodes(rest) };
     }
-}
 
-impl<T> Drop for LinkedList<T> {
-    fn drop(&mut self) {
-        let mut node = self.head;
-        while let Some(n) = node {
-            node = unsafe { Box::from_raw(n.as_ptr()) }.next;
+    /// Resizes the list in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the list is extended by the
+    /// difference, with each additional slot filled with the result of
+    /// calling the closure `f`. If `new_len` is less than `len`, the list
+    /// is simply truncated.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = (1..4).collect();
+    ///
+    /// list.resize_with(5, Default::default);
+    /// assert_eq!(list, [1, 2, 3, 0, 0]);
+    ///
+    /// let mut n = 0;
+    /// list.resize_with(7, || { n += 1; n });
+    /// assert_eq!(list, [1, 2, 3, 0, 0, 1, 2]);
+    ///
+    /// list.resize_with(2, Default::default);
+    /// assert_eq!(list, [1, 2]);
+    /// ```
+    pub fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        if new_len > self.len {
+            let additional = new_len - self.len;
+            self.push_back_iter(iter::repeat_with(f).take(additional));
+        } else {
+            self.truncate(new_len);
         }
     }
-}
-
-impl<T> Index<usize> for LinkedList<T> {
-    type Output = T;
 
-    /// Provides a reference to the element at position `index`.
-    ///
-    /// This operation should compute in *O*(*n*) time.
+    /// Clones and appends all the elements of a slice to the list.
     ///
-    /// # Panics
+    /// The new nodes are linked one after the other while walking the
+    /// slice once.
     ///
-    /// Panics if `index` >= `len`.
+    /// This operation should compute in *O*(*m*) time, where *m* is the
+    /// length of `other`.
     ///
     /// # Examples
     ///
@@ -469,128 +1566,3536 @@ impl<T> Index<usize> for LinkedList<T> {
     /// use collections::linked_list::LinkedList;
     ///
     /// let mut list = LinkedList::new();
+    /// list.push_back(1);
+    ///
+    /// list.extend_from_slice(&[2, 3, 4]);
+    /// assert_eq!(list, [1, 2, 3, 4]);
+    /// ```
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.push_back_iter(other.iter().cloned());
+    }
+
+    /// Returns `true` if the `LinkedList` contains an element equal to the
+    /// given value.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
     ///
-    /// list.push_front(5);
-    /// list.push_back(3);
-    /// assert_eq!(list[0], 5);
-    /// assert_eq!(list[1], 3);
     /// ```
-    /// ```should_panic
     /// use collections::linked_list::LinkedList;
     ///
     /// let mut list = LinkedList::new();
     ///
-    /// let n: u32 = list[5];
+    /// list.push_back(0);
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// assert_eq!(list.contains(&0), true);
+    /// assert_eq!(list.contains(&10), false);
     /// ```
-    fn index(&self, index: usize) -> &T {
-        self.get(index).expect("no element at that position")
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|e| e == x)
     }
-}
 
-impl<T> IndexMut<usize> for LinkedList<T> {
-    /// Provides a mutable reference to the element at position `index`.
-    ///
-    /// This operation should compute in *O*(*n*) time.
+    /// Moves all elements from `other` to the end of the list.
     ///
-    /// # Panics
+    /// This reuses all the nodes from `other` and moves them into `self`.
+    /// After this operation, `other` becomes empty.
     ///
-    /// Panics if `index` >= `len`.
+    /// This operation should compute in *O*(1) time.
     ///
     /// # Examples
     ///
     /// ```
     /// use collections::linked_list::LinkedList;
     ///
-    /// let mut list = LinkedList::new();
+    /// let mut list1 = LinkedList::new();
+    /// list1.push_back('a');
+    ///
+    /// let mut list2 = LinkedList::new();
+    /// list2.push_back('b');
+    /// list2.push_back('c');
+    ///
+    /// list1.append(&mut list2);
+    ///
+    /// let mut iter = list1.iter();
+    /// assert_eq!(iter.next(), Some(&'a'));
+    /// assert_eq!(iter.next(), Some(&'b'));
+    /// assert_eq!(iter.next(), Some(&'c'));
+    /// assert!(iter.next().is_none());
+    ///
+    /// assert!(list2.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        if let Some(other_head) = other.head.take() {
+            match self.tail {
+                None => self.head = Some(other_head),
+                Some(tail) => unsafe { (*tail.as_ptr()).next = Some(other_head); },
+            }
+            self.tail = other.tail.take();
+            self.len += mem::replace(&mut other.len, 0);
+        }
+    }
+
+    /// Moves all elements from `other` to the beginning of the list.
+    ///
+    /// This reuses all the nodes from `other` and moves them into `self`.
+    /// After this operation, `other` becomes empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
     ///
-    /// list.push_front(5);
-    /// list[0] = 1;
-    /// assert_eq!(list[0], 1);
     /// ```
-    /// ```should_panic
     /// use collections::linked_list::LinkedList;
     ///
-    /// let mut list = LinkedList::new();
+    /// let mut list1 = LinkedList::new();
+    /// list1.push_back('c');
     ///
-    /// list[3] = 12;
+    /// let mut list2 = LinkedList::new();
+    /// list2.push_back('a');
+    /// list2.push_back('b');
+    ///
+    /// list1.prepend(&mut list2);
+    ///
+    /// let mut iter = list1.iter();
+    /// assert_eq!(iter.next(), Some(&'a'));
+    /// assert_eq!(iter.next(), Some(&'b'));
+    /// assert_eq!(iter.next(), Some(&'c'));
+    /// assert!(iter.next().is_none());
+    ///
+    /// assert!(list2.is_empty());
     /// ```
-    fn index_mut(&mut self, index: usize) -> &mut T {
-        self.get_mut(index).expect("no element at that position")
+    pub fn prepend(&mut self, other: &mut Self) {
+        if let Some(other_tail) = other.tail.take() {
+            unsafe { (*other_tail.as_ptr()).next = self.head; }
+            if self.tail.is_none() {
+                self.tail = Some(other_tail);
+            }
+            self.head = other.head.take();
+            self.len += mem::replace(&mut other.len, 0);
+        }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use core::mem;
-    use super::*;
 
-    #[test]
-    fn empty() {
-        let list: LinkedList<u32> = LinkedList::new();
-        assert_eq!(list.len(), 0);
-        assert_eq!(list.front(), None);
-        assert_eq!(list.back(), None);
+    /// Reverses the order of the elements in place.
+    ///
+    /// No element is moved nor allocated, only the links are changed.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = (1..4).collect();
+    ///
+    /// list.reverse();
+    /// assert_eq!(list, [3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        let mut prev = None;
+        let mut node = self.head;
+        while let Some(n) = node {
+            node = mem::replace(unsafe { &mut (*n.as_ptr()).next }, prev);
+            prev = Some(n);
+        }
+        mem::swap(&mut self.head, &mut self.tail);
     }
 
-    #[test]
-    fn push_front() {
-        let mut list: LinkedList<u32> = LinkedList::new();
-
-        list.push_front(4);
-        assert_eq!(list.len(), 1);
-        assert_eq!(list.front(), Some(&4));
-        assert_eq!(list.back(), Some(&4));
-
-        list.push_front(6);
-        assert_eq!(list.len(), 2);
-        assert_eq!(list.front(), Some(&6));
+    /// Rotates the list in-place such that the first `mid` elements
+    /// move to the end.
+    ///
+    /// After calling `rotate_left`, the element previously at index `mid`
+    /// becomes the first element in the list.
+    ///
+    /// No element is moved nor allocated, only the links are changed.
+    ///
+    /// This operation should compute in *O*(`mid`) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` > `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<char> = "abcdef".chars().collect();
+    ///
+    /// list.rotate_left(2);
+    /// assert_eq!(list, ['c', 'd', 'e', 'f', 'a', 'b']);
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(
+            mid <= self.len,
+            "rotation (is {}) should be <= len (is {})",
+            mid,
+            self.len,
+        );
+
+        if mid == 0 || mid == self.len {
+            return;
+        }
+
+        let mut last = self.head.unwrap();
+        for _ in 1..mid {
+            last = unsafe { last.as_ref() }.next.unwrap();
+        }
+
+        unsafe {
+            (*self.tail.unwrap().as_ptr()).next = self.head;
+            self.head = (*last.as_ptr()).next.take();
+        }
+        self.tail = Some(last);
+    }
+
+    /// Rotates the list in-place such that the last `k` elements
+    /// move to the front.
+    ///
+    /// After calling `rotate_right`, the element previously at index
+    /// `len - k` becomes the first element in the list.
+    ///
+    /// No element is moved nor allocated, only the links are changed.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` > `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<char> = "abcdef".chars().collect();
+    ///
+    /// list.rotate_right(2);
+    /// assert_eq!(list, ['e', 'f', 'a', 'b', 'c', 'd']);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(
+            k <= self.len,
+            "rotation (is {}) should be <= len (is {})",
+            k,
+            self.len,
+        );
+
+        self.rotate_left(self.len - k);
+    }
+
+    /// Sorts the list.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and
+    /// *O*(*n* \* log(*n*)) worst-case.
+    ///
+    /// It is a bottom-up merge sort relinking the nodes:
+    /// no element is moved and nothing is allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = vec![-5, 4, 1, -3, 2].into_iter().collect();
+    ///
+    /// list.sort();
+    /// assert_eq!(list, [-5, -3, 1, 2, 4]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list with a comparator function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and
+    /// *O*(*n* \* log(*n*)) worst-case.
+    ///
+    /// If `compare` panics, the elements are left in an unspecified order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = vec![5, 4, 1, 3, 2].into_iter().collect();
+    ///
+    /// list.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(list, [5, 4, 3, 2, 1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        /// Sorted runs, `bins[i]` being empty or of length 2^i.
+        /// A bin holds elements placed before the ones of lower bins.
+        struct Guard<'a, T, A: Allocator> {
+            list: &'a mut LinkedList<T, A>,
+            carry: Chain<T>,
+            bins: [Chain<T>; 64],
+        }
+
+        impl<T, A: Allocator> Drop for Guard<'_, T, A> {
+            fn drop(&mut self) {
+                self.list.append_chain(&mut self.carry);
+                for bin in &mut self.bins {
+                    self.list.append_chain(bin);
+                }
+            }
+        }
+
+        let mut guard = Guard {
+            carry: Chain::EMPTY,
+            bins: [Chain::EMPTY; 64],
+            list: self,
+        };
+        let mut fill = 0;
+
+        while let Some(node) = guard.list.unlink_front_node() {
+            guard.carry.push_back(node);
+            let mut i = 0;
+            while i < fill && guard.bins[i].head.is_some() {
+                guard.bins[i].merge_sorted_by(&mut guard.carry, &mut compare);
+                mem::swap(&mut guard.carry, &mut guard.bins[i]);
+                i += 1;
+            }
+            mem::swap(&mut guard.carry, &mut guard.bins[i]);
+            if i == fill {
+                fill += 1;
+            }
+        }
+
+        for i in 1..fill {
+            let (lower, upper) = guard.bins.split_at_mut(i);
+            upper[0].merge_sorted_by(&mut lower[i - 1], &mut compare);
+        }
+    }
+
+    /// Sorts the list with a key extraction function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and
+    /// *O*(*m* \* *n* \* log(*n*)) worst-case, where the key function is
+    /// *O*(*m*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = vec![-5, 4, 1, -3, 2].into_iter().collect();
+    ///
+    /// list.sort_by_key(|k| k.abs());
+    /// assert_eq!(list, [1, 2, -3, 4, -5]);
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Merges the sorted list `other` into the sorted list `self`.
+    ///
+    /// The nodes are relinked: no element is moved, cloned nor allocated.
+    /// The merge is stable: elements of `self` are kept before equal
+    /// elements of `other`. After this operation, `other` becomes empty.
+    ///
+    /// If either list is not sorted, the order of the elements is
+    /// unspecified, but all of them end up in `self`.
+    ///
+    /// This operation should compute in *O*(*n* + *m*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut a: LinkedList<u32> = vec![1, 4, 6].into_iter().collect();
+    /// let mut b: LinkedList<u32> = vec![2, 3, 5, 7].into_iter().collect();
+    ///
+    /// a.merge(&mut b);
+    /// assert_eq!(a, [1, 2, 3, 4, 5, 6, 7]);
+    /// assert!(b.is_empty());
+    /// ```
+    pub fn merge(&mut self, other: &mut Self)
+    where
+        T: Ord,
+    {
+        self.merge_by(other, T::cmp);
+    }
+
+    /// Merges the list `other` into `self`, both being sorted according
+    /// to the comparator function `compare`.
+    ///
+    /// The nodes are relinked: no element is moved, cloned nor allocated.
+    /// The merge is stable: elements of `self` are kept before equal
+    /// elements of `other`. After this operation, `other` becomes empty.
+    ///
+    /// If `compare` panics, all the elements end up in `self`
+    /// in an unspecified order.
+    ///
+    /// This operation should compute in *O*(*n* + *m*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut a: LinkedList<u32> = vec![6, 4, 1].into_iter().collect();
+    /// let mut b: LinkedList<u32> = vec![7, 5, 3, 2].into_iter().collect();
+    ///
+    /// a.merge_by(&mut b, |x, y| y.cmp(x));
+    /// assert_eq!(a, [7, 6, 5, 4, 3, 2, 1]);
+    /// assert!(b.is_empty());
+    /// ```
+    pub fn merge_by<F>(&mut self, other: &mut Self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        struct Guard<'a, T, A: Allocator> {
+            list: &'a mut LinkedList<T, A>,
+            a: Chain<T>,
+            b: Chain<T>,
+        }
+
+        impl<T, A: Allocator> Drop for Guard<'_, T, A> {
+            fn drop(&mut self) {
+                self.list.append_chain(&mut self.a);
+                self.list.append_chain(&mut self.b);
+            }
+        }
+
+        let mut guard = Guard {
+            a: self.take_chain(),
+            b: other.take_chain(),
+            list: self,
+        };
+        guard.a.merge_sorted_by(&mut guard.b, &mut compare);
+    }
+
+    /// Returns the index at which `element` would be inserted by
+    /// [`insert_sorted`](Self::insert_sorted), the list being sorted.
+    ///
+    /// It is the index of the first element greater than `element`,
+    /// or `len` if there is none.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u32> = vec![1, 3, 3, 5].into_iter().collect();
+    ///
+    /// assert_eq!(list.find_insert_point(&0), 0);
+    /// assert_eq!(list.find_insert_point(&3), 3);
+    /// assert_eq!(list.find_insert_point(&4), 3);
+    /// assert_eq!(list.find_insert_point(&9), 4);
+    /// ```
+    pub fn find_insert_point(&self, element: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.iter().take_while(|x| *x <= element).count()
+    }
+
+    /// Inserts `element` into the sorted list, keeping it sorted.
+    ///
+    /// `element` is inserted after the elements equal to it.
+    ///
+    /// This operation should compute in *O*(*n*) time, or *O*(1) if
+    /// `element` is greater or equal to the back element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.insert_sorted(3);
+    /// list.insert_sorted(1);
+    /// list.insert_sorted(2);
+    /// list.insert_sorted(4);
+    /// assert_eq!(list, [1, 2, 3, 4]);
+    /// ```
+    pub fn insert_sorted(&mut self, element: T)
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(element, T::cmp);
+    }
+
+    /// Inserts `element` into the list sorted according to the comparator
+    /// function `compare`, keeping it sorted.
+    ///
+    /// `element` is inserted after the elements equal to it.
+    ///
+    /// This operation should compute in *O*(*n*) time, or *O*(1) if
+    /// `element` is greater or equal to the back element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.insert_sorted_by((2, 'a'), |a, b| a.0.cmp(&b.0));
+    /// list.insert_sorted_by((1, 'b'), |a, b| a.0.cmp(&b.0));
+    /// list.insert_sorted_by((2, 'c'), |a, b| a.0.cmp(&b.0));
+    /// assert_eq!(list, [(1, 'b'), (2, 'a'), (2, 'c')]);
+    /// ```
+    pub fn insert_sorted_by<F>(&mut self, element: T, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let at_back = match self.back() {
+            Some(back) => compare(back, &element) != Ordering::Greater,
+            None => true,
+        };
+        if at_back {
+            self.push_back(element);
+            return;
+        }
+
+        let mut prev = None;
+        let mut next = self.head;
+        while let Some(n) = next {
+            if compare(unsafe { &n.as_ref().element }, &element) == Ordering::Greater {
+                break;
+            }
+            prev = next;
+            next = unsafe { n.as_ref() }.next;
+        }
+
+        let node = self.alloc_node(element);
+        unsafe { self.link_after(prev, node) };
+    }
+
+    /// Converts the list into a list of the results of `f` applied to
+    /// each element, in order.
+    ///
+    /// When `T` and `U` have the same size and alignment, the nodes are
+    /// reused for the new elements instead of being reallocated.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u32> = (1..4).collect();
+    ///
+    /// let list = list.map(|x| x as f32 / 2.0);
+    /// assert_eq!(list, [0.5, 1.0, 1.5]);
+    ///
+    /// let list = list.map(|x| x.to_string());
+    /// assert_eq!(list, ["0.5", "1", "1.5"]);
+    /// ```
+    pub fn map<U, F>(mut self, mut f: F) -> LinkedList<U, A>
+    where
+        F: FnMut(T) -> U,
+        A: Clone,
+    {
+        /// Deallocates a node whose element was moved out,
+        /// if `f` panics.
+        struct Hole<'a, T, A: Allocator> {
+            node: NonNull<Node<T>>,
+            alloc: &'a A,
+        }
+
+        impl<T, A: Allocator> Drop for Hole<'_, T, A> {
+            fn drop(&mut self) {
+                unsafe {
+                    self.alloc.deallocate(self.node.cast(), Layout::new::<Node<T>>());
+                }
+            }
+        }
+
+        let mut mapped = LinkedList::new_in(self.alloc.clone());
+        if Layout::new::<Node<T>>() != Layout::new::<Node<U>>() {
+            mapped.push_back_iter(self.into_iter().map(f));
+            return mapped;
+        }
+
+        while let Some(node) = self.unlink_front_node() {
+            let element = unsafe { ptr::read(&(*node.as_ptr()).element) };
+            let hole = Hole {
+                node,
+                alloc: &self.alloc,
+            };
+            let element = f(element);
+            mem::forget(hole);
+
+            let node = node.cast::<Node<U>>();
+            unsafe { node.as_ptr().write(Node { element, next: None }); }
+            mapped.link_back_node(node);
+        }
+        mapped
+    }
+
+    /// Removes consecutive repeated elements in the list according to the
+    /// [`PartialEq`] trait implementation.
+    ///
+    /// If the list is sorted, this removes all duplicates.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = vec![1, 2, 2, 3, 2].into_iter().collect();
+    ///
+    /// list.dedup();
+    /// assert_eq!(list, [1, 2, 3, 2]);
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes all but the first of consecutive elements in the list that
+    /// resolve to the same key.
+    ///
+    /// If the list is sorted, this removes all duplicates.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = vec![10, 20, 21, 30, 20].into_iter().collect();
+    ///
+    /// list.dedup_by_key(|i| *i / 10);
+    /// assert_eq!(list, [10, 20, 30, 20]);
+    /// ```
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes all but the first of consecutive elements in the list
+    /// satisfying a given equality relation.
+    ///
+    /// The `same_bucket` function is passed references to two elements from
+    /// the list and must determine if the elements compare equal. The
+    /// elements are passed in opposite order from their order in the list,
+    /// so if `same_bucket(a, b)` returns `true`, `a` is removed.
+    ///
+    /// If the list is sorted, this removes all duplicates.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<&str> = vec!["foo", "bar", "Bar", "baz", "bar"]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(list, ["foo", "bar", "baz", "bar"]);
+    /// ```
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mut prev = match self.head {
+            Some(head) => head,
+            None => return,
+        };
+
+        while let Some(node) = unsafe { prev.as_ref() }.next {
+            let (a, b) = unsafe { (&mut (*node.as_ptr()).element, &mut (*prev.as_ptr()).element) };
+            if same_bucket(a, b) {
+                unsafe {
+                    let node = self.unlink_node(Some(prev), node);
+                    drop(self.free_node(node));
+                }
+            } else {
+                prev = node;
+            }
+        }
+    }
+
+    /// Creates an iterator which uses a closure to determine
+    /// if an element should be removed.
+    ///
+    /// If the closure returns true, then the element is removed and yielded.
+    /// If the closure returns false, the element will remain in the list
+    /// and will not be yielded by the iterator.
+    ///
+    /// If the returned `ExtractIf` is not exhausted, e.g. because it is
+    /// dropped without iterating or the iteration short-circuits, then the
+    /// remaining elements will be retained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut numbers: LinkedList<u32> = (1..10).collect();
+    ///
+    /// let evens: LinkedList<_> = numbers.extract_if(|x| *x % 2 == 0).collect();
+    /// let odds = numbers;
+    ///
+    /// assert_eq!(evens, [2, 4, 6, 8]);
+    /// assert_eq!(odds, [1, 3, 5, 7, 9]);
+    /// ```
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F, A>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf {
+            prev: None,
+            node: self.head,
+            remaining: self.len,
+            list: self,
+            pred,
+        }
+    }
+
+    /// Splits the list into two at the given index. Returns everything after
+    /// the given index, including the index.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` > `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_front(1);
+    /// list.push_front(2);
+    /// list.push_front(3);
+    ///
+    /// let mut split = list.split_off(2);
+    ///
+    /// assert_eq!(split.pop_front(), Some(1));
+    /// assert_eq!(split.pop_front(), None);
+    /// assert_eq!(list.pop_front(), Some(3));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        A: Clone,
+    {
+        assert!(
+            at <= self.len,
+            "split index (is {}) should be <= len (is {})",
+            at,
+            self.len,
+        );
+
+        if at == 0 {
+            let mut list = Self::new_in(self.alloc.clone());
+            list.append(self);
+            return list;
+        }
+
+        let mut last = self.head.unwrap();
+        for _ in 1..at {
+            last = unsafe { last.as_ref() }.next.unwrap();
+        }
+
+        let head = unsafe { (*last.as_ptr()).next.take() };
+        let tail = head.and(self.tail);
+        self.tail = Some(last);
+        let len = self.len - at;
+        self.len = at;
+
+        let mut list = Self::new_in(self.alloc.clone());
+        list.head = head;
+        list.tail = tail;
+        list.len = len;
+        list
+    }
+
+    /// Splits the list into two at the given index, consuming it.
+    ///
+    /// The first list contains the elements `[0, index)` and the second
+    /// one the elements `[index, len)`. The nodes are reused: nothing is
+    /// moved nor allocated.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` > `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u32> = (1..6).collect();
+    ///
+    /// let (left, right) = list.split_at(2);
+    /// assert_eq!(left, [1, 2]);
+    /// assert_eq!(right, [3, 4, 5]);
+    /// ```
+    pub fn split_at(mut self, index: usize) -> (Self, Self)
+    where
+        A: Clone,
+    {
+        let right = self.split_off(index);
+        (self, right)
+    }
+
+    /// Splits the list into two according to a predicate, consuming it.
+    ///
+    /// The first list contains the elements for which `f` returns `true`
+    /// and the second one the elements for which it returns `false`,
+    /// both in their original order. The nodes are relinked: nothing is
+    /// moved, cloned nor allocated.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u32> = (1..8).collect();
+    ///
+    /// let (even, odd) = list.partition(|x| x % 2 == 0);
+    /// assert_eq!(even, [2, 4, 6]);
+    /// assert_eq!(odd, [1, 3, 5, 7]);
+    /// ```
+    pub fn partition<F>(mut self, mut f: F) -> (Self, Self)
+    where
+        F: FnMut(&T) -> bool,
+        A: Clone,
+    {
+        let mut left = Self::new_in(self.alloc.clone());
+        let mut right = Self::new_in(self.alloc.clone());
+        while let Some(element) = self.front() {
+            let to_left = f(element);
+            let node = self.unlink_front_node().unwrap();
+            if to_left {
+                left.link_back_node(node);
+            } else {
+                right.link_back_node(node);
+            }
+        }
+        (left, right)
+    }
+
+    /// Insert an element at position `index` within the LinkedList.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` > `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.insert(0, 4);
+    /// assert_eq!(list.len(), 1);
+    /// assert_eq!(list.front(), Some(&4));
+    ///
+    /// list.insert(1, 2);
+    /// list.insert(1, 7);
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(list.front(), Some(&4));
+    /// assert_eq!(list.back(), Some(&2));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.insert(5, 6);
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) {
+        assert!(
+            index <= self.len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            self.len,
+        );
+
+        let mut before = None;
+        let mut after = self.head;
+        for _ in 0..index {
+            before = after;
+            after = unsafe { after.unwrap().as_ref() }.next;
+        }
+
+        let node = self.alloc_node(element);
+        unsafe { self.link_after(before, node) };
+    }
+
+    /// Insert an element at position `index` within the LinkedList,
+    /// or returns an error if `index` > `len`.
+    ///
+    /// This is the non-panicking counterpart of [`insert`](LinkedList::insert).
+    /// On error, `element` is dropped.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::{errors::InsertError, linked_list::LinkedList};
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// assert_eq!(list.try_insert(0, 4), Ok(()));
+    /// assert_eq!(list.front(), Some(&4));
+    ///
+    /// assert_eq!(list.try_insert(3, 2), Err(InsertError { index: 3, len: 1 }));
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), InsertError> {
+        if index > self.len {
+            return Err(InsertError { index, len: self.len });
+        }
+        self.insert(index, element);
+        Ok(())
+    }
+
+    /// Removes the element at position `index` and returns it.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// assert_eq!(list.remove(1), 2);
+    /// assert_eq!(list.remove(1), 3);
+    /// assert_eq!(list.back(), Some(&1));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    ///
+    /// list.remove(0);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "removal index (is {}) should be < len (is {})",
+            index,
+            self.len,
+        );
+
+        let mut before = None;
+        let mut node = self.head.unwrap();
+        for _ in 0..index {
+            before = Some(node);
+            node = unsafe { node.as_ref() }.next.unwrap();
+        }
+
+        unsafe {
+            let node = self.unlink_node(before, node);
+            self.free_node(node)
+        }
+    }
+
+    /// Removes the first element equal to `value` and returns it,
+    /// or `None` if there is no such element.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = vec![1, 2, 3, 2].into_iter().collect();
+    ///
+    /// assert_eq!(list.remove_item(&2), Some(2));
+    /// assert_eq!(list, [1, 3, 2]);
+    /// assert_eq!(list.remove_item(&4), None);
+    /// ```
+    pub fn remove_item<V>(&mut self, value: &V) -> Option<T>
+    where
+        T: PartialEq<V>,
+    {
+        let mut before = None;
+        let mut node = self.head;
+        while let Some(n) = node {
+            if unsafe { &n.as_ref().element } == value {
+                return Some(unsafe {
+                    let n = self.unlink_node(before, n);
+                    self.free_node(n)
+                });
+            }
+            before = node;
+            node = unsafe { n.as_ref() }.next;
+        }
+        None
+    }
+
+    /// Removes the specified range from the list, returning all removed
+    /// elements as an iterator.
+    ///
+    /// The range is unlinked from the list right away. If the iterator is
+    /// dropped before being fully consumed, it drops the remaining removed
+    /// elements.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = (1..6).collect();
+    ///
+    /// let drained: Vec<u32> = list.drain(1..3).collect();
+    /// assert_eq!(drained, [2, 3]);
+    /// assert_eq!(list, [1, 4, 5]);
+    ///
+    /// list.drain(..);
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, A>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (_, head, len) = self.unlink_range(range);
+        Drain {
+            list: self,
+            head,
+            len,
+        }
+    }
+
+    /// Replaces the specified range in the list with the given
+    /// `replace_with` iterator, returning the removed elements as an
+    /// iterator.
+    ///
+    /// The range is unlinked from the list right away. When the returned
+    /// iterator is dropped, the remaining removed elements are dropped and
+    /// the elements of `replace_with` are linked in place of the range.
+    /// No element is moved: each replacement element gets its own node.
+    ///
+    /// This operation should compute in *O*(*n* + *m*) time, where *m* is
+    /// the number of replacement elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = (1..6).collect();
+    ///
+    /// let removed: Vec<u32> = list.splice(1..3, [7, 8, 9]).collect();
+    /// assert_eq!(removed, [2, 3]);
+    /// assert_eq!(list, [1, 7, 8, 9, 4, 5]);
+    /// ```
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, A>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let (before, head, len) = self.unlink_range(range);
+        Splice {
+            drain: Drain {
+                list: self,
+                head,
+                len,
+            },
+            before,
+            replace_with: replace_with.into_iter(),
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(&e)` returns false.
+    /// This method operates in place, visiting each element exactly once in
+    /// the original order, and preserves the order of the retained elements.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = (1..7).collect();
+    ///
+    /// list.retain(|&x| x % 2 == 0);
+    /// assert_eq!(list, [2, 4, 6]);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|element| f(element));
+    }
+
+    /// Retains only the elements specified by the predicate,
+    /// passing a mutable reference to it.
+    ///
+    /// In other words, remove all elements `e` for which `f(&mut e)` returns
+    /// false. This method operates in place, visiting each element exactly
+    /// once in the original order, and preserves the order of the retained
+    /// elements.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = (1..7).collect();
+    ///
+    /// list.retain_mut(|x| if *x % 2 == 0 {
+    ///     *x += 1;
+    ///     true
+    /// } else {
+    ///     false
+    /// });
+    /// assert_eq!(list, [3, 5, 7]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let mut prev = None;
+        let mut node = self.head;
+        while let Some(n) = node {
+            node = unsafe { n.as_ref() }.next;
+            if f(unsafe { &mut (*n.as_ptr()).element }) {
+                prev = Some(n);
+            } else {
+                unsafe {
+                    let n = self.unlink_node(prev, n);
+                    drop(self.free_node(n));
+                }
+            }
+        }
+    }
+
+    /// Converts the list into a [`Vec`], the front element being first.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3]);
+    ///
+    /// let vec = list.into_vec();
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend(self);
+        vec
+    }
+
+    /// Returns a view of the list implementing `defmt::Format`,
+    /// printing at most `max` elements.
+    ///
+    /// The `defmt::Format` implementation of `LinkedList` itself prints
+    /// at most [`DEFMT_MAX_ELEMENTS`](crate::DEFMT_MAX_ELEMENTS) elements.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    ///
+    /// // Logs `[1, 2, ..]`
+    /// defmt::info!("{}", list.defmt_prefix(2));
+    /// ```
+    #[cfg(feature = "defmt")]
+    #[inline]
+    pub fn defmt_prefix(&self, max: usize) -> DefmtPrefix<'_, T, A> {
+        DefmtPrefix { list: self, max }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    /// Creates an empty `LinkedList`.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for LinkedList<T, A> {
+    /// Returns a deep copy of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// let mut copy = list.clone();
+    /// copy.push_back(3);
+    /// assert_eq!(list.len(), 2);
+    /// assert_eq!(copy.len(), 3);
+    /// ```
+    fn clone(&self) -> Self {
+        let mut list = Self::new_in(self.alloc.clone());
+        list.push_back_iter(self.iter().cloned());
+        list
+    }
+
+    /// Overwrites the list with a copy of `source`,
+    /// reusing the existing nodes.
+    fn clone_from(&mut self, source: &Self) {
+        let mut source_iter = source.iter();
+        for (element, source_element) in self.iter_mut().zip(&mut source_iter) {
+            element.clone_from(source_element);
+        }
+
+        if self.len > source.len {
+            self.truncate(source.len);
+        } else {
+            self.push_back_iter(source_iter.cloned());
+        }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for LinkedList<T, A> {
+    /// Formats the list like a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u32> = (1..4).collect();
+    /// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialOrd, A: Allocator> PartialOrd for LinkedList<T, A> {
+    /// Compares the lists lexicographically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let a: LinkedList<u32> = (1..3).collect();
+    /// let b: LinkedList<u32> = (1..4).collect();
+    /// let c: LinkedList<u32> = (2..3).collect();
+    /// assert!(a < b);
+    /// assert!(b < c);
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<T: Ord, A: Allocator> Ord for LinkedList<T, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other)
+    }
+}
+
+impl<T: Hash, A: Allocator> Hash for LinkedList<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for element in self {
+            element.hash(state);
+        }
+    }
+}
+
+#[cfg(not(feature = "may_dangle"))]
+impl<T, A: Allocator> Drop for LinkedList<T, A> {
+    fn drop(&mut self) {
+        self.drop_nodes();
+    }
+}
+
+// The elements are only dropped, never accessed: `marker` tells
+// dropck that they are owned.
+#[cfg(feature = "may_dangle")]
+unsafe impl<#[may_dangle] T, A: Allocator> Drop for LinkedList<T, A> {
+    fn drop(&mut self) {
+        self.drop_nodes();
+    }
+}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for LinkedList<T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for LinkedList<T, A> {}
+
+// A `NodeRef` can only be dereferenced through its list.
+unsafe impl<T> Send for NodeRef<T> {}
+
+unsafe impl<T> Sync for NodeRef<T> {}
+
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Send for Cursor<'_, T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Cursor<'_, T, A> {}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for CursorMut<'_, T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for CursorMut<'_, T, A> {}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for Drain<'_, T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Drain<'_, T, A> {}
+
+unsafe impl<I, A> Send for Splice<'_, I, A>
+where
+    I: Iterator + Send,
+    I::Item: Send,
+    A: Allocator + Send,
+{}
+
+unsafe impl<I, A> Sync for Splice<'_, I, A>
+where
+    I: Iterator + Sync,
+    I::Item: Sync,
+    A: Allocator + Sync,
+{}
+
+unsafe impl<T, F, A> Send for ExtractIf<'_, T, F, A>
+where
+    T: Send,
+    F: FnMut(&mut T) -> bool + Send,
+    A: Allocator + Send,
+{}
+
+unsafe impl<T, F, A> Sync for ExtractIf<'_, T, F, A>
+where
+    T: Sync,
+    F: FnMut(&mut T) -> bool + Sync,
+    A: Allocator + Sync,
+{}
+
+impl<T, A: Allocator> Index<usize> for LinkedList<T, A> {
+    type Output = T;
+
+    /// Provides a reference to the element at position `index`.
+    /// [`get`](LinkedList::get) doesn't panic if there is no element
+    /// at that position.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_front(5);
+    /// list.push_back(3);
+    /// assert_eq!(list[0], 5);
+    /// assert_eq!(list[1], 3);
+    /// ```
+    /// ```should_panic
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// let n: u32 = list[5];
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        let len = self.len;
+        match self.get(index) {
+            Some(element) => element,
+            None => panic!("index out of bounds: the len is {} but the index is {}", len, index),
+        }
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for LinkedList<T, A> {
+    /// Provides a mutable reference to the element at position `index`.
+    /// [`get_mut`](LinkedList::get_mut) doesn't panic if there is no element
+    /// at that position.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_front(5);
+    /// list[0] = 1;
+    /// assert_eq!(list[0], 1);
+    /// ```
+    /// ```should_panic
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list[3] = 12;
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(element) => element,
+            None => panic!("index out of bounds: the len is {} but the index is {}", len, index),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            let node = unsafe { &*node.as_ptr() };
+            self.head = node.next;
+            self.len -= 1;
+            &node.element
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter").field(&self.len).finish()
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            let node = unsafe { &mut *node.as_ptr() };
+            self.head = node.next;
+            self.len -= 1;
+            &mut node.element
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+impl<'a, T, A: Allocator> Cursor<'a, T, A> {
+    /// Returns the cursor position index within the `LinkedList`.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Moves the cursor to the next element of the `LinkedList`.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will
+    /// move it to the first element of the `LinkedList`. If it is pointing
+    /// to the last element of the `LinkedList` then this will move it to the
+    /// "ghost" non-element.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(current) => {
+                self.current = unsafe { current.as_ref() }.next;
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Returns a reference to the element that the cursor is currently
+    /// pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[inline]
+    pub fn current(&self) -> Option<&'a T> {
+        self.current.map(|current| unsafe { &(*current.as_ptr()).element })
+    }
+
+    /// Returns a reference to the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element of the `LinkedList`. If it is pointing to the last
+    /// element of the `LinkedList` then this returns `None`.
+    #[inline]
+    pub fn peek_next(&self) -> Option<&'a T> {
+        let next = match self.current {
+            Some(current) => unsafe { current.as_ref() }.next,
+            None => self.list.head,
+        };
+        next.map(|next| unsafe { &(*next.as_ptr()).element })
+    }
+}
+
+impl<T, A: Allocator> Clone for Cursor<'_, T, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Cursor { ..*self }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Cursor<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Cursor")
+            .field(self.list)
+            .field(&self.index())
+            .finish()
+    }
+}
+
+impl<'a, T, A: Allocator> CursorMut<'a, T, A> {
+    /// Returns a read-only cursor pointing to the current element.
+    ///
+    /// The lifetime of the returned `Cursor` is bound to that of the
+    /// `CursorMut`, which means it cannot outlive the `CursorMut` and that the
+    /// `CursorMut` is frozen for the lifetime of the `Cursor`.
+    #[inline]
+    pub fn as_cursor(&self) -> Cursor<'_, T, A> {
+        Cursor {
+            index: self.index,
+            current: self.current,
+            list: self.list,
+        }
+    }
+
+    /// Returns the cursor position index within the `LinkedList`.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Moves the cursor to the next element of the `LinkedList`.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will
+    /// move it to the first element of the `LinkedList`. If it is pointing
+    /// to the last element of the `LinkedList` then this will move it to the
+    /// "ghost" non-element.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(current) => {
+                self.prev = Some(current);
+                self.current = unsafe { current.as_ref() }.next;
+                self.index += 1;
+            }
+            None => {
+                self.prev = None;
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Returns a reference to the element that the cursor is currently
+    /// pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[inline]
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|current| unsafe { &mut (*current.as_ptr()).element })
+    }
+
+    /// Returns a reference to the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element of the `LinkedList`. If it is pointing to the last
+    /// element of the `LinkedList` then this returns `None`.
+    #[inline]
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            Some(current) => unsafe { current.as_ref() }.next,
+            None => self.list.head,
+        };
+        next.map(|next| unsafe { &mut (*next.as_ptr()).element })
+    }
+
+    /// Inserts a new element into the `LinkedList` after the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new
+    /// element is inserted at the front of the `LinkedList`.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn insert_after(&mut self, item: T) {
+        match self.current {
+            Some(current) => {
+                let node = self.list.alloc_node(item);
+                unsafe { (*node.as_ptr()).next = current.as_ref().next; }
+                let node = Some(node);
+
+                unsafe { (*current.as_ptr()).next = node; }
+                if self.list.tail == Some(current) {
+                    self.list.tail = node;
+                }
+                self.list.len += 1;
+            }
+            None => {
+                self.list.push_front(item);
+                self.prev = self.list.tail;
+                self.index += 1;
+            }
+        }
+    }
+
+    /// Removes the current element from the `LinkedList`.
+    ///
+    /// The element that was removed is returned, and the cursor is
+    /// moved to point to the next element in the `LinkedList`.
+    ///
+    /// If the cursor is currently pointing to the "ghost" non-element then no
+    /// element is removed and `None` is returned.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current?;
+        self.current = unsafe { current.as_ref() }.next;
+        unsafe {
+            let current = self.list.unlink_node(self.prev, current);
+            Some(self.list.free_node(current))
+        }
+    }
+
+    /// Splits the list into two after the current element. This will
+    /// return a new list consisting of everything after the cursor, with the
+    /// original list retaining everything before.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the entire
+    /// contents of the `LinkedList` are moved.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn split_after(&mut self) -> LinkedList<T, A>
+    where
+        A: Clone,
+    {
+        match self.current {
+            Some(current) => {
+                let head = unsafe { (*current.as_ptr()).next.take() };
+                let tail = head.and(self.list.tail);
+                let len = self.list.len - self.index - 1;
+                self.list.tail = Some(current);
+                self.list.len = self.index + 1;
+
+                let mut list = LinkedList::new_in(self.list.alloc.clone());
+                list.head = head;
+                list.tail = tail;
+                list.len = len;
+                list
+            }
+            None => {
+                self.prev = None;
+                self.index = 0;
+                let mut list = LinkedList::new_in(self.list.alloc.clone());
+                list.append(self.list);
+                list
+            }
+        }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for CursorMut<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CursorMut")
+            .field(&self.list)
+            .field(&self.index())
+            .finish()
+    }
+}
+
+impl<T, F, A: Allocator> Iterator for ExtractIf<'_, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(node) = self.node {
+            self.node = unsafe { node.as_ref() }.next;
+            self.remaining -= 1;
+            if (self.pred)(unsafe { &mut (*node.as_ptr()).element }) {
+                return Some(unsafe {
+                    let node = self.list.unlink_node(self.prev, node);
+                    self.list.free_node(node)
+                });
+            }
+            self.prev = Some(node);
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<T, F, A: Allocator> FusedIterator for ExtractIf<'_, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{}
+
+impl<T, A: Allocator> Iterator for Drain<'_, T, A> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let node = self.head?;
+        self.head = unsafe { node.as_ref() }.next;
+        self.len -= 1;
+        Some(unsafe { self.list.free_node(node) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Drain<'_, T, A> {}
+
+impl<T, A: Allocator> FusedIterator for Drain<'_, T, A> {}
+
+impl<T, A: Allocator> Drop for Drain<'_, T, A> {
+    fn drop(&mut self) {
+        let head = self.head.take();
+        unsafe { self.list.free_nodes(head) };
+    }
+}
+
+impl<T, A: Allocator> fmt::Debug for Drain<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.len).finish()
+    }
+}
+
+impl<I: Iterator, A: Allocator> Iterator for Splice<'_, I, A> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.drain.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<I: Iterator, A: Allocator> ExactSizeIterator for Splice<'_, I, A> {}
+
+impl<I: Iterator, A: Allocator> FusedIterator for Splice<'_, I, A> {}
+
+impl<I: Iterator, A: Allocator> Drop for Splice<'_, I, A> {
+    fn drop(&mut self) {
+        self.drain.by_ref().for_each(drop);
+
+        for element in &mut self.replace_with {
+            let list = &mut *self.drain.list;
+            let node = list.alloc_node(element);
+            unsafe { list.link_after(self.before, node) };
+            self.before = Some(node);
+        }
+    }
+}
+
+impl<I: Iterator, A: Allocator> fmt::Debug for Splice<'_, I, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Splice").field(&self.drain.len).finish()
+    }
+}
+
+impl<T> Clone for NodeRef<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodeRef<T> {}
+
+impl<T> PartialEq for NodeRef<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node
+    }
+}
+
+impl<T> Eq for NodeRef<T> {}
+
+impl<T> Hash for NodeRef<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for NodeRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NodeRef").field(&self.node).finish()
+    }
+}
+
+impl<T: fmt::Debug, F, A: Allocator> fmt::Debug for ExtractIf<'_, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExtractIf").field(&self.list).finish()
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for IntoIter<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.list).finish()
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for LinkedList<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    /// Consumes the list into an iterator yielding elements by value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    ///
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// let mut iter = list.into_iter();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> IntoIter<T, A> {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    /// Creates a `LinkedList` from an iterator,
+    /// the first element being at the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u32> = (1..4).collect();
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(list.front(), Some(&1));
+    /// assert_eq!(list.back(), Some(&3));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.push_back_iter(iter.into_iter());
+        list
+    }
+}
+
+impl<T> From<Vec<T>> for LinkedList<T> {
+    /// Creates a `LinkedList` from a `Vec`,
+    /// the first element being at the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from(vec![1, 2, 3]);
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    /// Creates a `LinkedList` from an array,
+    /// the first element being at the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        IntoIterator::into_iter(array).collect()
+    }
+}
+
+impl<T, A: Allocator> From<LinkedList<T, A>> for Vec<T> {
+    /// Converts a `LinkedList` into a `Vec`,
+    /// see [`LinkedList::into_vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let vec = Vec::from(LinkedList::from([1, 2, 3]));
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn from(list: LinkedList<T, A>) -> Self {
+        list.into_vec()
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for LinkedList<T, A> {
+    /// Appends the elements of an iterator to the back of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push_back(1);
+    ///
+    /// list.extend(vec![2, 3]);
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(list.back(), Some(&3));
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.push_back_iter(iter.into_iter());
+    }
+}
+
+impl<'a, T: 'a + Copy, A: Allocator> Extend<&'a T> for LinkedList<T, A> {
+    /// Appends copies of the elements of an iterator
+    /// to the back of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push_back(1);
+    ///
+    /// list.extend(&[2, 3]);
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(list.back(), Some(&3));
+    /// ```
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.push_back_iter(iter.into_iter().copied());
+    }
+}
+
+impl<T> fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IterMut").field(&self.len).finish()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a LinkedList<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut LinkedList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[doc(no_inline)]
+pub use crate::DEFMT_MAX_ELEMENTS;
+
+/// A view of a [`LinkedList`] implementing `defmt::Format`,
+/// printing only the first elements.
+///
+/// This `struct` is created by [`LinkedList::defmt_prefix`].
+#[cfg(feature = "defmt")]
+pub struct DefmtPrefix<'a, T, A: Allocator = Global> {
+    list: &'a LinkedList<T, A>,
+    max: usize,
+}
+
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format, A: Allocator> defmt::Format for DefmtPrefix<'_, T, A> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        crate::format::defmt_prefix(f, self.list.iter(), self.max);
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format, A: Allocator> defmt::Format for LinkedList<T, A> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        self.defmt_prefix(crate::DEFMT_MAX_ELEMENTS).format(f)
+    }
+}
+
+impl_slice_eq! { [A: Allocator, B: Allocator] LinkedList<T, A>, LinkedList<U, B> }
+impl_slice_eq! { [A: Allocator] LinkedList<T, A>, [U] }
+impl_slice_eq! { [A: Allocator] LinkedList<T, A>, &[U] }
+impl_slice_eq! { [A: Allocator] LinkedList<T, A>, &mut [U] }
+impl_slice_eq! { [A: Allocator, const N: usize] LinkedList<T, A>, [U; N] }
+impl_slice_eq! { [A: Allocator] LinkedList<T, A>, Vec<U> }
+impl_slice_eq! { [A: Allocator] [T], LinkedList<U, A> }
+impl_slice_eq! { [A: Allocator] &[T], LinkedList<U, A> }
+impl_slice_eq! { [A: Allocator] &mut [T], LinkedList<U, A> }
+impl_slice_eq! { [A: Allocator, const N: usize] [T; N], LinkedList<U, A> }
+impl_slice_eq! { [A: Allocator] Vec<T>, LinkedList<U, A> }
+
+impl<T: Eq, A: Allocator> Eq for LinkedList<T, A> {}
+
+#[cfg(test)]
+mod tests {
+    use core::mem;
+    use alloc::{format, vec};
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let list: LinkedList<u32> = LinkedList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn push_front() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+
+        list.push_front(4);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.front(), Some(&4));
+        assert_eq!(list.back(), Some(&4));
+
+        list.push_front(6);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.front(), Some(&6));
+        assert_eq!(list.back(), Some(&4));
+    }
+
+    #[test]
+    fn push_back() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+
+        list.push_back(4);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.front(), Some(&4));
+        assert_eq!(list.back(), Some(&4));
+
+        list.push_back(6);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.front(), Some(&4));
+        assert_eq!(list.back(), Some(&6));
+    }
+
+    #[test]
+    fn pop_front() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+
+        assert_eq!(list.pop_front(), None);
+
+        list.push_front(12);
+        list.push_front(7);
+
+        assert_eq!(list.pop_front(), Some(7));
+        assert_eq!(list.pop_front(), Some(12));
+        assert_eq!(list.pop_front(), None);
+
+        mem::forget(list);
+    }
+
+    #[test]
+    fn pop_back() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+
+        assert_eq!(list.pop_back(), None);
+
+        list.push_back(12);
+        list.push_back(7);
+
+        assert_eq!(list.pop_back(), Some(7));
+        assert_eq!(list.pop_back(), Some(12));
+        assert_eq!(list.pop_back(), None);
+
+        mem::forget(list);
+    }
+
+    #[test]
+    fn eq_slices() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        let empty: [u32; 0] = [];
+        assert!(list == empty);
+        assert!(list == Vec::<u32>::new());
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert!(list == [1, 2, 3]);
+        let slice: &[u32] = &[1, 2, 3];
+        assert!(list == slice);
+        assert!(list == slice[..]);
+        assert!(list == vec![1, 2, 3]);
+        assert!(vec![1, 2, 3] == list);
+        assert!([1, 2, 3] == list);
+        assert!(slice == list);
+        assert!(slice[..] == list);
+        assert!(list != [1, 2]);
+        assert!(list != [1, 2, 4]);
+        assert!([1, 2, 3, 4] != list);
+    }
+
+    #[test]
+    fn try_insert() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+
+        assert_eq!(list.try_insert(1, 3), Err(InsertError { index: 1, len: 0 }));
+        assert_eq!(list.try_insert(0, 3), Ok(()));
+        assert_eq!(list.try_insert(1, 5), Ok(()));
+        assert_eq!(list.try_insert(3, 5), Err(InsertError { index: 3, len: 2 }));
+        assert!(list == [3, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 3) should be <= len (is 2)")]
+    fn insert_out_of_bounds() {
+        let mut list: LinkedList<u32> = (0..2).collect();
+        list.insert(3, 0);
+    }
+
+    #[test]
+    fn macro_literal() {
+        let list: LinkedList<u32> = linked_list![];
+        assert_eq!(list.len(), 0);
+
+        let list = linked_list![4, 5, 6,];
+        assert!(list == [4, 5, 6]);
+
+        let list = linked_list![7; 3];
+        assert!(list == [7, 7, 7]);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        assert_eq!(list.iter().next(), None);
+
+        list.push_back(4);
+        list.push_back(5);
+        list.push_front(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        assert_eq!(list.iter_mut().next(), None);
+
+        list.push_back(4);
+        list.push_back(5);
+        list.push_front(3);
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        let first = iter.next().unwrap();
+        let second = iter.next().unwrap();
+        *first *= 2;
+        *second *= 2;
+        assert_eq!(iter.next(), Some(&mut 5));
+        assert_eq!(iter.next(), None);
+        assert!(list == [6, 8, 5]);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+
+        let mut sum = 0;
+        for x in iter {
+            sum += x;
+        }
+        assert_eq!(sum, 5);
+    }
+
+    #[test]
+    fn into_iter_ref() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+
+        for x in &mut list {
+            *x += 1;
+        }
+
+        let mut sum = 0;
+        for x in &list {
+            sum += x;
+        }
+        assert_eq!(sum, 5);
+    }
+
+    #[test]
+    fn from_iter() {
+        let list: LinkedList<u32> = core::iter::empty().collect();
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+
+        let mut list: LinkedList<u32> = (0..5).collect();
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.back(), Some(&4));
+        assert!(list == [0, 1, 2, 3, 4]);
+
+        list.push_back(5);
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.back(), Some(&4));
+    }
+
+    #[test]
+    fn extend() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.extend(core::iter::empty::<u32>());
+        assert_eq!(list.len(), 0);
+
+        list.extend(vec![1, 2]);
+        list.extend(&[3, 4]);
+        list.extend(Vec::<u32>::new());
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&4));
+        assert!(list == [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn clone() {
+        let list: LinkedList<u32> = (0..4).collect();
+        let copy = list.clone();
+        assert!(copy == [0, 1, 2, 3]);
+        assert_eq!(copy.back(), Some(&3));
+
+        let empty: LinkedList<u32> = LinkedList::new();
+        assert_eq!(empty.clone().len(), 0);
+    }
+
+    #[test]
+    fn clone_from() {
+        let short: LinkedList<u32> = (0..2).collect();
+        let long: LinkedList<u32> = (10..15).collect();
+        let empty: LinkedList<u32> = LinkedList::new();
+
+        let mut list = short.clone();
+        list.clone_from(&long);
+        assert!(list == [10, 11, 12, 13, 14]);
+        assert_eq!(list.back(), Some(&14));
+
+        list.clone_from(&short);
+        assert!(list == [0, 1]);
+        assert_eq!(list.back(), Some(&1));
+        list.push_back(2);
+        assert!(list == [0, 1, 2]);
+
+        list.clone_from(&empty);
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+
+        list.clone_from(&short);
+        assert!(list == [0, 1]);
+    }
+
+    #[test]
+    fn debug() {
+        let list: LinkedList<u32> = LinkedList::new();
+        assert_eq!(format!("{:?}", list), "[]");
+
+        let list: LinkedList<u32> = (1..4).collect();
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+        assert_eq!(format!("{:?}", list.iter()), "Iter(3)");
+        assert_eq!(format!("{:?}", list.into_iter()), "IntoIter([1, 2, 3])");
+    }
+
+    #[test]
+    fn eq() {
+        let a: LinkedList<u32> = (0..3).collect();
+        let b: LinkedList<u32> = (0..3).collect();
+        let c: LinkedList<u32> = (0..4).collect();
+        let d: LinkedList<u32> = (1..4).collect();
+        let empty: LinkedList<u32> = LinkedList::new();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(c, a);
+        assert_ne!(a, d);
+        assert_ne!(a, empty);
+        assert_eq!(empty, LinkedList::new());
+    }
+
+    #[test]
+    fn hash() {
+        use core::hash::BuildHasher;
+        use crate::hash::FixedState;
+
+        fn hash<T: Hash>(x: &T) -> u64 {
+            FixedState::default().hash_one(x)
+        }
+
+        let a: LinkedList<u32> = (0..3).collect();
+        let b: LinkedList<u32> = (0..3).collect();
+        let c: LinkedList<u32> = (1..4).collect();
+        assert_eq!(hash(&a), hash(&b));
+        assert_ne!(hash(&a), hash(&c));
+
+        let one: LinkedList<u32> = (1..2).collect();
+        let nested_a: LinkedList<_> = vec![one.clone(), LinkedList::new()].into_iter().collect();
+        let nested_b: LinkedList<_> = vec![LinkedList::new(), one].into_iter().collect();
+        assert_ne!(hash(&nested_a), hash(&nested_b));
+    }
+
+    #[test]
+    fn ord() {
+        let empty: LinkedList<f64> = LinkedList::new();
+        let a: LinkedList<f64> = vec![1.0, 2.0].into_iter().collect();
+        let b: LinkedList<f64> = vec![1.0, f64::NAN].into_iter().collect();
+        assert!(empty < a);
+        assert_eq!(a.partial_cmp(&a), Some(Ordering::Equal));
+        assert_eq!(a.partial_cmp(&b), None);
+
+        let mut lists: Vec<LinkedList<u32>> = vec![
+            (2..3).collect(),
+            (0..3).collect(),
+            LinkedList::new(),
+            (0..2).collect(),
+        ];
+        lists.sort();
+        assert_eq!(lists, [
+            LinkedList::new(),
+            (0..2).collect(),
+            (0..3).collect(),
+            (2..3).collect(),
+        ]);
+    }
+
+    #[test]
+    fn default() {
+        let mut list: LinkedList<u32> = Default::default();
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        assert!(!list.is_empty());
+        list.pop_back();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn contains() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        assert!(!list.contains(&0));
+
+        list.extend(&[3, 1, 4]);
+        assert!(list.contains(&3));
+        assert!(list.contains(&4));
+        assert!(!list.contains(&2));
+    }
+
+    #[test]
+    fn remove() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+
+        assert_eq!(list.remove(0), 0);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.remove(3), 4);
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.remove(1), 2);
+        assert_eq!(list, [1, 3]);
+        assert_eq!(list.len(), 2);
+
+        list.push_back(5);
+        assert_eq!(list, [1, 3, 5]);
+
+        assert_eq!(list.remove(0), 1);
+        assert_eq!(list.remove(0), 3);
+        assert_eq!(list.remove(0), 5);
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn append() {
+        let mut a: LinkedList<u32> = LinkedList::new();
+        let mut b: LinkedList<u32> = LinkedList::new();
+        a.append(&mut b);
+        assert!(a.is_empty());
+        assert!(b.is_empty());
+
+        b.extend(&[1, 2]);
+        a.append(&mut b);
+        assert_eq!(a, [1, 2]);
+        assert!(b.is_empty());
+        assert_eq!(b.back(), None);
+
+        a.append(&mut b);
+        assert_eq!(a, [1, 2]);
+
+        b.extend(&[3, 4]);
+        a.append(&mut b);
+        assert_eq!(a, [1, 2, 3, 4]);
+        assert_eq!(a.len(), 4);
+        assert_eq!(a.back(), Some(&4));
+        assert!(b.is_empty());
+
+        a.push_back(5);
+        b.push_back(6);
+        assert_eq!(a, [1, 2, 3, 4, 5]);
+        assert_eq!(b, [6]);
+    }
+
+    #[test]
+    fn prepend() {
+        let mut a: LinkedList<u32> = LinkedList::new();
+        let mut b: LinkedList<u32> = LinkedList::new();
+        a.prepend(&mut b);
+        assert!(a.is_empty());
+
+        b.extend(&[3, 4]);
+        a.prepend(&mut b);
+        assert_eq!(a, [3, 4]);
+        assert!(b.is_empty());
+
+        b.extend(&[1, 2]);
+        a.prepend(&mut b);
+        assert_eq!(a, [1, 2, 3, 4]);
+        assert_eq!(a.back(), Some(&4));
+        assert!(b.is_empty());
+        assert_eq!(b.front(), None);
+
+        a.push_back(5);
+        a.push_front(0);
+        assert_eq!(a, [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+
+        let mut tail = list.split_off(5);
+        assert!(tail.is_empty());
+        assert_eq!(tail.back(), None);
+        assert_eq!(list.len(), 5);
+
+        tail = list.split_off(3);
+        assert_eq!(list, [0, 1, 2]);
+        assert_eq!(list.back(), Some(&2));
+        assert_eq!(tail, [3, 4]);
+        assert_eq!(tail.back(), Some(&4));
+
+        let all = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None);
+        assert_eq!(all, [0, 1, 2]);
+
+        list.push_back(7);
+        tail.push_back(5);
+        assert_eq!(list, [7]);
+        assert_eq!(tail, [3, 4, 5]);
+    }
+
+    #[test]
+    fn retain() {
+        let mut list: LinkedList<u32> = (0..10).collect();
+
+        list.retain(|&x| x % 3 != 0);
+        assert_eq!(list, [1, 2, 4, 5, 7, 8]);
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.back(), Some(&8));
+
+        list.retain(|&x| x < 5);
+        assert_eq!(list, [1, 2, 4]);
         assert_eq!(list.back(), Some(&4));
+        list.push_back(9);
+        assert_eq!(list, [1, 2, 4, 9]);
+
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn retain_mut() {
+        let mut list: LinkedList<u32> = (0..6).collect();
+
+        list.retain_mut(|x| {
+            *x *= 10;
+            *x != 30
+        });
+        assert_eq!(list, [0, 10, 20, 40, 50]);
     }
 
     #[test]
-    fn push_back() {
+    fn extract_if() {
+        let mut list: LinkedList<u32> = (0..10).collect();
+
+        let extracted: Vec<u32> = list.extract_if(|x| *x % 3 == 0).collect();
+        assert_eq!(extracted, [0, 3, 6, 9]);
+        assert_eq!(list, [1, 2, 4, 5, 7, 8]);
+        assert_eq!(list.back(), Some(&8));
+
+        assert_eq!(list.extract_if(|x| *x > 3).next(), Some(4));
+        assert_eq!(list, [1, 2, 5, 7, 8]);
+
+        let extracted: Vec<u32> = list.extract_if(|_| true).collect();
+        assert_eq!(extracted, [1, 2, 5, 7, 8]);
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn reverse() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.reverse();
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        list.reverse();
+        assert_eq!(list, [1]);
+        assert_eq!(list.back(), Some(&1));
+
+        list.extend(&[2, 3, 4]);
+        list.reverse();
+        assert_eq!(list, [4, 3, 2, 1]);
+        assert_eq!(list.front(), Some(&4));
+        assert_eq!(list.back(), Some(&1));
+
+        list.push_back(0);
+        list.push_front(5);
+        assert_eq!(list, [5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn sort() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.sort();
+        assert!(list.is_empty());
+
+        // A small linear congruential generator
+        let mut x = 7u32;
+        let mut next = || {
+            x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (x >> 16) % 100
+        };
+
+        for len in [1, 2, 3, 7, 8, 9, 100, 257].iter().copied() {
+            let mut v: Vec<u32> = (0..len).map(|_| next()).collect();
+            let mut list: LinkedList<u32> = v.iter().copied().collect();
+            v.sort();
+            list.sort();
+            assert_eq!(list, v);
+            assert_eq!(list.back(), v.last());
+            assert_eq!(list.len(), v.len());
+        }
+    }
+
+    #[test]
+    fn sort_stable() {
+        let mut list: LinkedList<(u32, usize)> = (0..50)
+            .map(|i| ((i * 7) % 5, i as usize))
+            .collect();
+        list.sort_by_key(|&(k, _)| k);
+
+        let v: Vec<_> = list.into_iter().collect();
+        for pair in v.windows(2) {
+            assert!(pair[0].0 < pair[1].0 || (pair[0].0 == pair[1].0 && pair[0].1 < pair[1].1));
+        }
+    }
+
+    #[test]
+    fn sort_panic() {
+        extern crate std;
+
+        let mut list: LinkedList<u32> = (0..20).rev().collect();
+        let mut calls = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                calls += 1;
+                if calls == 30 {
+                    panic!("comparison failed");
+                }
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.len(), 20);
+
+        let mut v: Vec<u32> = list.into_iter().collect();
+        v.sort();
+        assert_eq!(v, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn cursor_mut_navigation() {
+        let mut list: LinkedList<u32> = (0..3).collect();
+        let mut cursor = list.cursor_front_mut();
+
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current(), Some(&mut 0));
+        assert_eq!(cursor.peek_next(), Some(&mut 1));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.peek_next(), None);
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&mut 0));
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+        *cursor.current().unwrap() = 10;
+        assert_eq!(list, [10, 1, 2]);
+
+        let mut empty: LinkedList<u32> = LinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+    }
+
+    #[test]
+    fn cursor_mut_insert_remove() {
         let mut list: LinkedList<u32> = LinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_after(2);
+        cursor.insert_after(1);
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.insert_after(3);
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list.back(), Some(&3));
 
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_after(0);
+        assert_eq!(list, [0, 1, 2]);
+        assert_eq!(list.back(), Some(&2));
+
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.remove_current(), Some(0));
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.remove_current(), None);
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None);
         list.push_back(4);
-        assert_eq!(list.len(), 1);
+        assert_eq!(list, [4]);
+    }
+
+    #[test]
+    fn cursor_mut_split_after() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        let mut tail = cursor.split_after();
+        assert_eq!(cursor.index(), Some(1));
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(list, [0, 1]);
+        assert_eq!(list.back(), Some(&1));
+        assert_eq!(tail, [2, 3, 4]);
+        assert_eq!(tail.len(), 3);
+        assert_eq!(tail.back(), Some(&4));
+
+        let mut cursor = tail.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert!(cursor.split_after().is_empty());
+        cursor.move_next();
+        let all = cursor.split_after();
+        assert_eq!(all, [2, 3, 4]);
+        assert!(tail.is_empty());
+        assert_eq!(tail.back(), None);
+    }
+
+    #[test]
+    fn cursor() {
+        let list: LinkedList<u32> = (0..3).collect();
+        let mut cursor = list.cursor_front();
+
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current(), Some(&0));
+        assert_eq!(cursor.peek_next(), Some(&1));
+
+        let saved = cursor.clone();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.peek_next(), None);
+        assert_eq!(saved.index(), Some(0));
+
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&0));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&0));
+
+        let empty: LinkedList<u32> = LinkedList::new();
+        let cursor = empty.cursor_front();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.peek_next(), None);
+    }
+
+    #[test]
+    fn cursor_mut_as_cursor() {
+        let mut list: LinkedList<u32> = (0..3).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        let view = cursor.as_cursor();
+        assert_eq!(view.index(), Some(1));
+        assert_eq!(view.current(), Some(&1));
+        assert_eq!(view.peek_next(), Some(&2));
+    }
+
+    #[test]
+    fn get() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        assert_eq!(list.get(0), None);
+        assert_eq!(list.get_mut(0), None);
+
+        list.extend(&[3, 5, 7]);
+        assert_eq!(list.get(0), Some(&3));
+        assert_eq!(list.get(2), Some(&7));
+        assert_eq!(list.get(3), None);
+        assert_eq!(list.get(usize::MAX), None);
+
+        *list.get_mut(1).unwrap() = 6;
+        assert_eq!(list.get_mut(3), None);
+        assert_eq!(list, [3, 6, 7]);
+    }
+
+    #[test]
+    fn swap() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+
+        list.swap(0, 4);
+        assert_eq!(list, [4, 1, 2, 3, 0]);
+        list.swap(3, 1);
+        assert_eq!(list, [4, 3, 2, 1, 0]);
+        list.swap(2, 2);
+        assert_eq!(list, [4, 3, 2, 1, 0]);
         assert_eq!(list.front(), Some(&4));
+        assert_eq!(list.back(), Some(&0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_out_of_bounds() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+        list.swap(1, 5);
+    }
+
+    #[test]
+    fn dedup() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.dedup();
+        assert!(list.is_empty());
+
+        list.extend(&[1, 1, 1, 2, 3, 3, 1, 4, 4]);
+        list.dedup();
+        assert_eq!(list, [1, 2, 3, 1, 4]);
+        assert_eq!(list.len(), 5);
         assert_eq!(list.back(), Some(&4));
+        list.push_back(5);
+        assert_eq!(list, [1, 2, 3, 1, 4, 5]);
 
-        list.push_back(6);
-        assert_eq!(list.len(), 2);
-        assert_eq!(list.front(), Some(&4));
-        assert_eq!(list.back(), Some(&6));
+        let mut list: LinkedList<u32> = (0..10).collect();
+        list.dedup_by_key(|x| *x / 4);
+        assert_eq!(list, [0, 4, 8]);
+        assert_eq!(list.back(), Some(&8));
     }
 
     #[test]
-    fn pop_front() {
+    fn rotate() {
         let mut list: LinkedList<u32> = LinkedList::new();
+        list.rotate_left(0);
+        list.rotate_right(0);
+        assert!(list.is_empty());
 
-        assert_eq!(list.pop_front(), None);
+        let mut list: LinkedList<u32> = (0..5).collect();
+        list.rotate_left(5);
+        assert_eq!(list, [0, 1, 2, 3, 4]);
+        list.rotate_left(1);
+        assert_eq!(list, [1, 2, 3, 4, 0]);
+        assert_eq!(list.back(), Some(&0));
+        list.rotate_right(3);
+        assert_eq!(list, [3, 4, 0, 1, 2]);
+        assert_eq!(list.back(), Some(&2));
+        list.push_back(5);
+        assert_eq!(list, [3, 4, 0, 1, 2, 5]);
+        assert_eq!(list.len(), 6);
+    }
 
-        list.push_front(12);
-        list.push_front(7);
+    #[test]
+    #[should_panic(expected = "rotation (is 4) should be <= len (is 3)")]
+    fn rotate_out_of_bounds() {
+        let mut list: LinkedList<u32> = (0..3).collect();
+        list.rotate_left(4);
+    }
 
-        assert_eq!(list.pop_front(), Some(7));
-        assert_eq!(list.pop_front(), Some(12));
-        assert_eq!(list.pop_front(), None);
+    #[test]
+    fn truncate() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+        list.truncate(5);
+        assert_eq!(list, [0, 1, 2, 3, 4]);
+        list.truncate(3);
+        assert_eq!(list, [0, 1, 2]);
+        assert_eq!(list.len(), 3);
+        list.push_back(7);
+        assert_eq!(list, [0, 1, 2, 7]);
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None);
+    }
 
-        mem::forget(list);
+    #[test]
+    fn resize_with() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.resize_with(0, || unreachable!());
+        assert!(list.is_empty());
+        list.resize_with(3, || 7);
+        assert_eq!(list, [7, 7, 7]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.back(), Some(&7));
+        list.resize_with(3, || unreachable!());
+        assert_eq!(list, [7, 7, 7]);
+        list.resize_with(1, || unreachable!());
+        assert_eq!(list, [7]);
+        list.push_back(8);
+        assert_eq!(list, [7, 8]);
     }
 
     #[test]
-    fn pop_back() {
+    fn merge() {
+        let mut a: LinkedList<u32> = LinkedList::new();
+        let mut b: LinkedList<u32> = (0..3).collect();
+        a.merge(&mut b);
+        assert_eq!(a, [0, 1, 2]);
+        assert!(b.is_empty());
+        a.merge(&mut b);
+        assert_eq!(a, [0, 1, 2]);
+        assert_eq!(a.back(), Some(&2));
+
+        let mut a: LinkedList<(u32, char)> = vec![(1, 'a'), (2, 'a'), (4, 'a')].into_iter().collect();
+        let mut b: LinkedList<(u32, char)> = vec![(0, 'b'), (2, 'b'), (5, 'b')].into_iter().collect();
+        a.merge_by(&mut b, |x, y| x.0.cmp(&y.0));
+        assert_eq!(a, [(0, 'b'), (1, 'a'), (2, 'a'), (2, 'b'), (4, 'a'), (5, 'b')]);
+        assert_eq!(a.len(), 6);
+        assert_eq!(a.back(), Some(&(5, 'b')));
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn split_at() {
+        let list: LinkedList<u32> = (0..4).collect();
+        let (left, right) = list.split_at(0);
+        assert!(left.is_empty());
+        assert_eq!(right, [0, 1, 2, 3]);
+
+        let (mut left, mut right) = right.split_at(4);
+        assert_eq!(left, [0, 1, 2, 3]);
+        assert!(right.is_empty());
+
+        left.push_back(4);
+        right.push_back(5);
+        let (left, right) = left.split_at(3);
+        assert_eq!(left, [0, 1, 2]);
+        assert_eq!(left.back(), Some(&2));
+        assert_eq!(right, [3, 4]);
+        assert_eq!(right.len(), 2);
+    }
+
+    #[test]
+    fn partition() {
+        let list: LinkedList<u32> = LinkedList::new();
+        let (left, right) = list.partition(|_| unreachable!());
+        assert!(left.is_empty());
+        assert!(right.is_empty());
+
+        let list: LinkedList<u32> = (0..10).collect();
+        let (mut left, right) = list.partition(|x| *x < 3 || *x > 7);
+        assert_eq!(left, [0, 1, 2, 8, 9]);
+        assert_eq!(right, [3, 4, 5, 6, 7]);
+        assert_eq!(left.len(), 5);
+        assert_eq!(right.back(), Some(&7));
+        left.push_back(10);
+        assert_eq!(left, [0, 1, 2, 8, 9, 10]);
+
+        let list: LinkedList<u32> = (0..3).collect();
+        let (left, right) = list.partition(|_| true);
+        assert_eq!(left, [0, 1, 2]);
+        assert!(right.is_empty());
+    }
+
+    #[test]
+    fn conversions() {
+        let mut list = LinkedList::from(vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.back(), Some(&3));
+        list.push_back(4);
+        assert_eq!(Vec::from(list), [1, 2, 3, 4]);
+
+        let list = LinkedList::from([0u32; 0]);
+        assert!(list.is_empty());
+        assert!(list.into_vec().is_empty());
+
+        let list = LinkedList::from([vec![1], vec![2, 3]]);
+        let vec: Vec<Vec<u32>> = list.into();
+        assert_eq!(vec, [vec![1], vec![2, 3]]);
+    }
+
+    #[cfg(feature = "allocator_api")]
+    #[test]
+    fn allocator() {
+        use core::cell::Cell;
+        use alloc::alloc::{AllocError, Allocator, Global};
+
+        #[derive(Clone, Copy)]
+        struct Counting<'a>(&'a Cell<usize>);
+
+        unsafe impl Allocator for Counting<'_> {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+                self.0.set(self.0.get() + 1);
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                self.0.set(self.0.get() - 1);
+                Global.deallocate(ptr, layout)
+            }
+        }
+
+        let live = Cell::new(0);
+        let mut list = LinkedList::new_in(Counting(&live));
+        list.extend([3, 1, 2]);
+        list.push_front(0);
+        assert_eq!(live.get(), 4);
+
+        list.sort();
+        let mut other = list.split_off(2);
+        assert_eq!(list, [0, 1]);
+        assert_eq!(other, [2, 3]);
+        assert_eq!(other.pop_front(), Some(2));
+        assert_eq!(live.get(), 3);
+
+        list.append(&mut other);
+        let clone = list.clone();
+        assert_eq!(live.get(), 6);
+        drop(clone);
+        list.truncate(1);
+        assert_eq!(live.get(), 1);
+        drop(list);
+        assert_eq!(live.get(), 0);
+    }
+
+    #[cfg(feature = "allocator_api")]
+    #[test]
+    fn sort_unique_allocator() {
+        use alloc::alloc::{AllocError, Allocator, Global};
+
+        /// An allocator that can't be cloned.
+        struct Unique;
+
+        unsafe impl Allocator for Unique {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                Global.deallocate(ptr, layout)
+            }
+        }
+
+        let mut list = LinkedList::new_in(Unique);
+        list.extend([3, 1, 4, 1, 5]);
+        list.sort();
+        assert_eq!(list, [1, 1, 3, 4, 5]);
+        list.sort_by_key(|&x| x % 3);
+        assert_eq!(list, [3, 1, 1, 4, 5]);
+
+        let mut other = LinkedList::new_in(Unique);
+        other.extend([0, 2, 6]);
+        list.merge(&mut other);
+        assert_eq!(list, [0, 2, 3, 1, 1, 4, 5, 6]);
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn try_push() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.try_push_back(2), Ok(()));
+        assert_eq!(list.try_push_front(1), Ok(()));
+        assert_eq!(list.try_push_back(3), Ok(()));
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list.back(), Some(&3));
+    }
+
+    #[cfg(feature = "allocator_api")]
+    #[test]
+    fn try_push_alloc_error() {
+        use core::cell::Cell;
+        use alloc::{alloc::{AllocError, Allocator, Global}, rc::Rc};
+
+        #[derive(Clone, Copy)]
+        struct Limited<'a>(&'a Cell<usize>);
+
+        unsafe impl Allocator for Limited<'_> {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+                let left = self.0.get().checked_sub(1).ok_or(AllocError)?;
+                self.0.set(left);
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                self.0.set(self.0.get() + 1);
+                Global.deallocate(ptr, layout)
+            }
+        }
+
+        let left = Cell::new(2);
+        let element = Rc::new(());
+        let mut list = LinkedList::new_in(Limited(&left));
+        assert_eq!(list.try_push_back(element.clone()), Ok(()));
+        assert_eq!(list.try_push_front(element.clone()), Ok(()));
+        assert_eq!(list.try_push_back(element.clone()), Err(crate::errors::AllocError));
+        assert_eq!(list.try_push_front(element.clone()), Err(crate::errors::AllocError));
+        assert_eq!(list.len(), 2);
+        assert_eq!(Rc::strong_count(&element), 3);
+
+        list.pop_front();
+        assert_eq!(list.try_push_front(element.clone()), Ok(()));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut list: LinkedList<u32> = (0..3).collect();
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.by_ref().count(), 2);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = list.iter_mut();
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+
+        let mut extract = list.extract_if(|x| *x == 0);
+        assert_eq!(extract.size_hint(), (0, Some(3)));
+        assert_eq!(extract.next(), Some(0));
+        assert_eq!(extract.size_hint(), (0, Some(2)));
+        assert_eq!(extract.next(), None);
+        assert_eq!(extract.size_hint(), (0, Some(0)));
+        assert_eq!(extract.next(), None);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.len(), 2);
+        iter.next();
+        assert_eq!(iter.len(), 1);
+        iter.next();
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn send_sync() {
+        fn is_send<T: Send>(_: &T) {}
+        fn is_sync<T: Sync>(_: &T) {}
+
+        let mut list: LinkedList<u32> = (0..3).collect();
+        is_send(&list);
+        is_sync(&list);
+        is_send(&list.iter());
+        is_sync(&list.iter());
+        is_send(&list.iter_mut());
+        is_sync(&list.iter_mut());
+        is_send(&list.cursor_front());
+        is_sync(&list.cursor_front());
+        is_send(&list.cursor_front_mut());
+        is_sync(&list.cursor_front_mut());
+        is_send(&list.extract_if(|_| false));
+        is_sync(&list.extract_if(|_| false));
+        is_send(&list.clone().into_iter());
+        is_sync(&list.clone().into_iter());
+
+        extern crate std;
+        let sum = std::thread::spawn(move || list.iter().sum::<u32>()).join();
+        assert_eq!(sum.unwrap(), 3);
+    }
+
+    #[test]
+    fn handles() {
+        let mut list = LinkedList::new();
+        let b = list.push_back_handle('b');
+        let a = list.push_front_handle('a');
+        let c = list.push_back_handle('c');
+        list.push_back('d');
+        assert_ne!(a, b);
+        assert_eq!(a, a.clone());
+
+        unsafe {
+            assert_eq!(list.get_handle(a), &'a');
+            assert_eq!(list.get_handle(c), &'c');
+
+            assert_eq!(list.remove_handle(b), 'b');
+            assert_eq!(list, ['a', 'c', 'd']);
+            assert_eq!(list.remove_handle(a), 'a');
+            assert_eq!(list, ['c', 'd']);
+            assert_eq!(list.front(), Some(&'c'));
+        }
+
+        let d = list.push_back_handle('e');
+        unsafe {
+            assert_eq!(list.remove_handle(d), 'e');
+            assert_eq!(list.back(), Some(&'d'));
+            list.push_back('f');
+            assert_eq!(list.remove_handle(c), 'c');
+        }
+        assert_eq!(list, ['d', 'f']);
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn pool() {
+        let mut list = LinkedList::with_pool(3);
+        list.extend([1, 2, 3, 4]);
+        let last = list.back().unwrap() as *const u32;
+        list.truncate(1);
+        assert_eq!(list.pool_len(), 3);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pool_len(), 3);
+
+        list.push_back(5);
+        assert_eq!(list.pool_len(), 2);
+        assert_eq!(list.front().unwrap() as *const u32, last);
+
+        let mut other: LinkedList<u32> = (6..8).collect();
+        list.append(&mut other);
+        assert_eq!(list, [5, 6, 7]);
+        list.clear();
+        assert_eq!(list.pool_len(), 3);
+
+        let mut other: LinkedList<u32> = (0..2).collect();
+        list.prepend(&mut other);
+        let mut split = list.split_off(0);
+        assert_eq!(split, [0, 1]);
+        assert_eq!(list.pool_len(), 3);
+        split.clear();
+        assert_eq!(split.pool_len(), 0);
+
+        list.shrink_pool();
+        assert_eq!(list.pool_len(), 0);
+        list.extend([8, 9]);
+        assert_eq!(list, [8, 9]);
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let mut list = LinkedList::new();
+        for x in [5, 1, 4, 1, 5, 9, 2, 6] {
+            list.insert_sorted(x);
+        }
+        assert_eq!(list, [1, 1, 2, 4, 5, 5, 6, 9]);
+        assert_eq!(list.len(), 8);
+        assert_eq!(list.back(), Some(&9));
+        assert_eq!(list.find_insert_point(&5), 6);
+
+        let mut list: LinkedList<(u32, char)> = LinkedList::new();
+        for x in [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (3, 'e')] {
+            list.insert_sorted_by(x, |a, b| a.0.cmp(&b.0));
+        }
+        assert_eq!(list, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c'), (3, 'e')]);
+        list.push_back((4, 'f'));
+        assert_eq!(list.back(), Some(&(4, 'f')));
+    }
+
+    #[test]
+    fn remove_item() {
         let mut list: LinkedList<u32> = LinkedList::new();
+        assert_eq!(list.remove_item(&0), None);
 
-        assert_eq!(list.pop_back(), None);
+        list.extend([1, 2, 3, 1]);
+        assert_eq!(list.remove_item(&1), Some(1));
+        assert_eq!(list, [2, 3, 1]);
+        assert_eq!(list.remove_item(&1), Some(1));
+        assert_eq!(list, [2, 3]);
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.remove_item(&3), Some(3));
+        assert_eq!(list.back(), Some(&2));
+        assert_eq!(list.len(), 1);
 
-        list.push_back(12);
-        list.push_back(7);
+        let mut list: LinkedList<&str> = vec!["a", "b"].into_iter().collect();
+        assert_eq!(list.remove_item(&"b"), Some("b"));
+    }
 
-        assert_eq!(list.pop_back(), Some(7));
-        assert_eq!(list.pop_back(), Some(12));
-        assert_eq!(list.pop_back(), None);
+    #[test]
+    fn drain() {
+        let mut list: LinkedList<u32> = (0..6).collect();
+        assert_eq!(list.drain(2..2).next(), None);
+        assert_eq!(list.len(), 6);
 
-        mem::forget(list);
+        let mut drain = list.drain(..=1);
+        assert_eq!(drain.len(), 2);
+        assert_eq!(drain.next(), Some(0));
+        drop(drain);
+        assert_eq!(list, [2, 3, 4, 5]);
+
+        let drained: Vec<u32> = list.drain(2..).collect();
+        assert_eq!(drained, [4, 5]);
+        assert_eq!(list, [2, 3]);
+        assert_eq!(list.back(), Some(&3));
+        list.push_back(6);
+        assert_eq!(list, [2, 3, 6]);
+
+        let drained: Vec<u32> = list.drain((Bound::Excluded(0), Bound::Excluded(2))).collect();
+        assert_eq!(drained, [3]);
+        assert_eq!(list, [2, 6]);
+        assert_eq!(list.len(), 2);
+
+        list.drain(..);
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end (is 4) should be <= len (is 3)")]
+    fn drain_out_of_bounds() {
+        let mut list: LinkedList<u32> = (0..3).collect();
+        list.drain(1..4);
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        let mut list: LinkedList<Vec<u32>> = LinkedList::new();
+        list.extend_from_slice(&[]);
+        assert!(list.is_empty());
+
+        list.extend_from_slice(&[vec![1], vec![2, 3]]);
+        list.extend_from_slice(&[vec![]]);
+        assert_eq!(list, [vec![1], vec![2, 3], vec![]]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.back(), Some(&vec![]));
+    }
+
+    #[test]
+    fn map() {
+        let list: LinkedList<u32> = (0..4).collect();
+        let addresses: Vec<*const u32> = list.iter().map(|x| x as *const u32).collect();
+
+        let mut list = list.map(|x| x as i32 - 2);
+        assert_eq!(list, [-2, -1, 0, 1]);
+        let reused: Vec<*const u32> = list.iter().map(|x| x as *const i32 as *const u32).collect();
+        assert_eq!(reused, addresses);
+        list.push_back(2);
+        assert_eq!(list.back(), Some(&2));
+        assert_eq!(list.len(), 5);
+
+        let list = list.map(|x| (x, x));
+        assert_eq!(list, [(-2, -2), (-1, -1), (0, 0), (1, 1), (2, 2)]);
+
+        let list: LinkedList<u32> = LinkedList::new();
+        assert!(list.map(|x| x as u8).is_empty());
+    }
+
+    #[test]
+    fn map_panic() {
+        extern crate std;
+        use alloc::rc::Rc;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let rc = Rc::new(());
+        let list: LinkedList<Rc<()>> = (0..4).map(|_| rc.clone()).collect();
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.map(|x| {
+                calls += 1;
+                if calls == 3 {
+                    panic!("map");
+                }
+                x
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn splice() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+        assert_eq!(list.splice(1..1, [9]).count(), 0);
+        assert_eq!(list, [0, 9, 1, 2, 3, 4]);
+
+        let mut splice = list.splice(..2, [7, 8]);
+        assert_eq!(splice.len(), 2);
+        assert_eq!(splice.next(), Some(0));
+        drop(splice);
+        assert_eq!(list, [7, 8, 1, 2, 3, 4]);
+
+        let removed: Vec<u32> = list.splice(4.., 5..8).collect();
+        assert_eq!(removed, [3, 4]);
+        assert_eq!(list, [7, 8, 1, 2, 5, 6, 7]);
+        assert_eq!(list.back(), Some(&7));
+
+        list.splice(2..4, []);
+        assert_eq!(list, [7, 8, 5, 6, 7]);
+        assert_eq!(list.len(), 5);
+
+        list.splice(.., [1]);
+        assert_eq!(list, [1]);
+        assert_eq!(list.back(), Some(&1));
+        list.splice(1.., [2, 3]);
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list.back(), Some(&3));
+        list.push_back(4);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[cfg(feature = "may_dangle")]
+    #[test]
+    fn may_dangle() {
+        // `x` is dropped before `list`.
+        let (mut list, x);
+        x = vec![1, 2, 3];
+        list = LinkedList::new();
+        list.push_back(&x);
+        assert_eq!(list, [&x]);
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn nodes() {
+        let mut a: LinkedList<u32> = (0..3).collect();
+        let mut b = LinkedList::new();
+
+        let node = a.pop_front_node().unwrap();
+        let addr = &*node as *const Node<u32>;
+        assert_eq!(*node.element(), 0);
+        b.push_back_node(node);
+        assert_eq!(b.front().unwrap() as *const u32, addr.cast());
+
+        let mut node = a.pop_front_node().unwrap();
+        *node.element_mut() = 4;
+        b.push_front_node(node);
+        b.push_back_node(Box::new(Node::new(5)));
+        assert_eq!(a, [2]);
+        assert_eq!(b, [4, 0, 5]);
+        assert_eq!(b.back(), Some(&5));
+
+        assert_eq!(a.pop_front_node().map(|node| node.into_element()), Some(2));
+        assert!(a.pop_front_node().is_none());
+        assert_eq!(a.back(), None);
+        assert_eq!(format!("{:?}", Node::new(1)), "Node(1)");
+    }
+
+    #[test]
+    fn index() {
+        let mut list: LinkedList<u32> = (0..4).collect();
+        for i in 0..4 {
+            list[i] *= 10;
+        }
+        assert_eq!(list[0], 0);
+        assert_eq!(list[3], 30);
+        assert_eq!(list, [0, 10, 20, 30]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 4 but the index is 4")]
+    fn index_out_of_bounds() {
+        let list: LinkedList<u32> = (0..4).collect();
+        let _ = list[4];
     }
 }
@@ -0,0 +1,22 @@
+//! Macros shared by the collections.
+
+/// Implements `PartialEq<$rhs> for $lhs` by comparing the lengths,
+/// then the elements in iteration order.
+///
+/// Both types must have `len` and `iter` methods. `T` and `U` are the
+/// element types, `$vars` the other generic parameters.
+macro_rules! impl_slice_eq {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<T, U, $($vars)*> PartialEq<$rhs> for $lhs
+        where
+            T: PartialEq<U>,
+        {
+            fn eq(&self, other: &$rhs) -> bool {
+                self.len() == other.len()
+                    && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_slice_eq;
@@ -0,0 +1,23 @@
+//! The collections prelude.
+//!
+//! Re-exports the collection types so that they can be imported
+//! all at once.
+//!
+//! # Examples
+//!
+//! ```
+//! use collections::prelude::*;
+//!
+//! let mut list = LinkedList::new();
+//! list.push_back(1);
+//!
+//! let mut deque = VecDeque::new();
+//! deque.push_front(1);
+//! ```
+
+#[cfg(feature = "alloc")]
+pub use crate::doubly_linked_list::DoublyLinkedList;
+#[cfg(feature = "alloc")]
+pub use crate::linked_list::LinkedList;
+#[cfg(feature = "alloc")]
+pub use crate::vec_deque::VecDeque;
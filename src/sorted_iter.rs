@@ -0,0 +1,373 @@
+//! Adapters combining iterators sorted in ascending order.
+//!
+//! All the adapters are lazy: they only pull from their inputs
+//! as needed. If an input is not sorted in ascending order,
+//! the result is unspecified but memory safe.
+
+use core::{cmp::Ordering, iter::Peekable};
+#[cfg(feature = "alloc")]
+use core::{iter::FusedIterator, mem};
+#[cfg(feature = "alloc")]
+use alloc::{
+    collections::{binary_heap::PeekMut, BinaryHeap},
+    vec::Vec,
+};
+
+/// Merges several sorted iterators into a single sorted iterator.
+///
+/// Elements comparing equal are yielded in the order of the iterators
+/// they come from.
+///
+/// Each call to `next` should compute in *O*(log *k*) time,
+/// where *k* is the number of iterators.
+///
+/// # Examples
+///
+/// ```
+/// use collections::sorted_iter::kmerge;
+///
+/// let merged: Vec<u32> = kmerge(vec![vec![1, 4, 7], vec![2, 5], vec![3, 6, 8]]).collect();
+/// assert_eq!(merged, [1, 2, 3, 4, 5, 6, 7, 8]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn kmerge<I>(iterables: I) -> KMerge<<I::Item as IntoIterator>::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: Ord,
+{
+    let heap = iterables
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, iterable)| {
+            let mut tail = iterable.into_iter();
+            tail.next().map(|head| HeadTail { head, index, tail })
+        })
+        .collect::<Vec<_>>()
+        .into();
+    KMerge { heap }
+}
+
+/// Yields the elements present in either of two sorted iterators.
+///
+/// When both iterators yield an equal element, only the one from `a`
+/// is kept.
+///
+/// # Examples
+///
+/// ```
+/// use collections::sorted_iter::union;
+///
+/// let u: Vec<u32> = union(vec![1, 3, 5], vec![2, 3, 4]).collect();
+/// assert_eq!(u, [1, 2, 3, 4, 5]);
+/// ```
+pub fn union<I, J>(a: I, b: J) -> Union<I::IntoIter, J::IntoIter>
+where
+    I: IntoIterator,
+    J: IntoIterator<Item = I::Item>,
+    I::Item: Ord,
+{
+    Union {
+        a: a.into_iter().peekable(),
+        b: b.into_iter().peekable(),
+    }
+}
+
+/// Yields the elements of `a` also present in `b`,
+/// both iterators being sorted.
+///
+/// # Examples
+///
+/// ```
+/// use collections::sorted_iter::intersection;
+///
+/// let i: Vec<u32> = intersection(vec![1, 3, 5, 7], vec![3, 4, 7]).collect();
+/// assert_eq!(i, [3, 7]);
+/// ```
+pub fn intersection<I, J>(a: I, b: J) -> Intersection<I::IntoIter, J::IntoIter>
+where
+    I: IntoIterator,
+    J: IntoIterator<Item = I::Item>,
+    I::Item: Ord,
+{
+    Intersection {
+        a: a.into_iter().peekable(),
+        b: b.into_iter().peekable(),
+    }
+}
+
+/// Yields the elements of `a` not present in `b`,
+/// both iterators being sorted.
+///
+/// # Examples
+///
+/// ```
+/// use collections::sorted_iter::difference;
+///
+/// let d: Vec<u32> = difference(vec![1, 3, 5, 7], vec![3, 4, 7]).collect();
+/// assert_eq!(d, [1, 5]);
+/// ```
+pub fn difference<I, J>(a: I, b: J) -> Difference<I::IntoIter, J::IntoIter>
+where
+    I: IntoIterator,
+    J: IntoIterator<Item = I::Item>,
+    I::Item: Ord,
+{
+    Difference {
+        a: a.into_iter(),
+        b: b.into_iter().peekable(),
+    }
+}
+
+/// An iterator merging several sorted iterators.
+///
+/// This `struct` is created by [`kmerge`].
+#[cfg(feature = "alloc")]
+pub struct KMerge<I: Iterator> {
+    heap: BinaryHeap<HeadTail<I>>,
+}
+
+/// An iterator with its next element pulled out,
+/// ordered so that the `BinaryHeap` is a min-heap on `head`.
+#[cfg(feature = "alloc")]
+struct HeadTail<I: Iterator> {
+    head: I::Item,
+    index: usize,
+    tail: I,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> PartialEq for HeadTail<I>
+where
+    I::Item: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> Eq for HeadTail<I> where I::Item: Ord {}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> PartialOrd for HeadTail<I>
+where
+    I::Item: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> Ord for HeadTail<I>
+where
+    I::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.head.cmp(&self.head)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Iterator for KMerge<I>
+where
+    I: Iterator,
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let mut top = self.heap.peek_mut()?;
+        match top.tail.next() {
+            Some(next) => Some(mem::replace(&mut top.head, next)),
+            None => Some(PeekMut::pop(top).head),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.heap.iter().fold((0, Some(0)), |(lo, hi), ht| {
+            let (tail_lo, tail_hi) = ht.tail.size_hint();
+            (
+                lo.saturating_add(tail_lo).saturating_add(1),
+                hi.and_then(|hi| hi.checked_add(tail_hi?)?.checked_add(1)),
+            )
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> FusedIterator for KMerge<I>
+where
+    I: Iterator,
+    I::Item: Ord,
+{}
+
+/// An iterator over the union of two sorted iterators.
+///
+/// This `struct` is created by [`union`].
+pub struct Union<I: Iterator, J: Iterator> {
+    a: Peekable<I>,
+    b: Peekable<J>,
+}
+
+impl<T: Ord, I, J> Iterator for Union<I, J>
+where
+    I: Iterator<Item = T>,
+    J: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let ordering = match (self.a.peek(), self.b.peek()) {
+            (Some(x), Some(y)) => x.cmp(y),
+            (Some(_), None) => Ordering::Less,
+            (None, _) => Ordering::Greater,
+        };
+        match ordering {
+            Ordering::Less => self.a.next(),
+            Ordering::Greater => self.b.next(),
+            Ordering::Equal => {
+                self.b.next();
+                self.a.next()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        (
+            a_lo.max(b_lo),
+            a_hi.and_then(|a_hi| a_hi.checked_add(b_hi?)),
+        )
+    }
+}
+
+/// An iterator over the intersection of two sorted iterators.
+///
+/// This `struct` is created by [`intersection`].
+pub struct Intersection<I: Iterator, J: Iterator> {
+    a: Peekable<I>,
+    b: Peekable<J>,
+}
+
+impl<T: Ord, I, J> Iterator for Intersection<I, J>
+where
+    I: Iterator<Item = T>,
+    J: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            match self.a.peek()?.cmp(self.b.peek()?) {
+                Ordering::Less => {
+                    self.a.next();
+                }
+                Ordering::Greater => {
+                    self.b.next();
+                }
+                Ordering::Equal => {
+                    self.b.next();
+                    return self.a.next();
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, a_hi) = self.a.size_hint();
+        let (_, b_hi) = self.b.size_hint();
+        let hi = match (a_hi, b_hi) {
+            (Some(a_hi), Some(b_hi)) => Some(a_hi.min(b_hi)),
+            (hi, None) | (None, hi) => hi,
+        };
+        (0, hi)
+    }
+}
+
+/// An iterator over the difference of two sorted iterators.
+///
+/// This `struct` is created by [`difference`].
+pub struct Difference<I: Iterator, J: Iterator> {
+    a: I,
+    b: Peekable<J>,
+}
+
+impl<T: Ord, I, J> Iterator for Difference<I, J>
+where
+    I: Iterator<Item = T>,
+    J: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        'outer: loop {
+            let x = self.a.next()?;
+            while let Some(y) = self.b.peek() {
+                match x.cmp(y) {
+                    Ordering::Less => break,
+                    Ordering::Greater => {
+                        self.b.next();
+                    }
+                    Ordering::Equal => continue 'outer,
+                }
+            }
+            return Some(x);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.a.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn kmerge_stable() {
+        let merged: Vec<(u32, char)> = kmerge(vec![
+            vec![(1, 'a'), (3, 'a')],
+            vec![],
+            vec![(1, 'c'), (2, 'c'), (3, 'c')],
+            vec![(0, 'd'), (3, 'd')],
+        ]).collect();
+        assert_eq!(merged, [
+            (0, 'd'), (1, 'a'), (1, 'c'), (2, 'c'), (3, 'a'), (3, 'c'), (3, 'd'),
+        ]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn kmerge_size_hint() {
+        let merged = kmerge(vec![vec![1, 2], vec![3], vec![]]);
+        assert_eq!(merged.size_hint(), (3, Some(3)));
+        assert_eq!(kmerge(Vec::<Vec<u32>>::new()).next(), None);
+    }
+
+    #[test]
+    fn union_empty() {
+        let u: Vec<u32> = union(vec![], vec![1, 2]).collect();
+        assert_eq!(u, [1, 2]);
+        let u: Vec<u32> = union(vec![1, 2], vec![]).collect();
+        assert_eq!(u, [1, 2]);
+    }
+
+    #[test]
+    fn intersection_disjoint() {
+        let i: Vec<u32> = intersection(vec![1, 3, 5], vec![0, 2, 4, 6]).collect();
+        assert_eq!(i, []);
+    }
+
+    #[test]
+    fn difference_tail() {
+        let d: Vec<u32> = difference(vec![1, 2, 3, 8, 9], vec![2, 3, 4]).collect();
+        assert_eq!(d, [1, 8, 9]);
+    }
+}
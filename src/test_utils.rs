@@ -0,0 +1,13 @@
+//! Fixtures shared by the unit tests of the collections.
+
+use core::cell::Cell;
+
+/// Increments the counter it borrows when dropped, to check that a
+/// collection drops each of its elements exactly once.
+pub(crate) struct DropCounter<'a>(pub(crate) &'a Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
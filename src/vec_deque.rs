@@ -0,0 +1,1938 @@
+//! A double-ended queue implemented with a growable ring buffer.
+
+use core::{
+    alloc::Layout,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{FromIterator, FusedIterator},
+    marker::PhantomData,
+    mem::{self, ManuallyDrop},
+    ops::{Index, IndexMut, Range},
+    ptr::{self, NonNull},
+    slice,
+};
+use alloc::{alloc::handle_alloc_error, vec::Vec};
+use crate::{
+    allocator::{Allocator, Global},
+    errors::{InsertError, TryReserveError},
+    doubly_linked_list::DoublyLinkedList,
+    linked_list::LinkedList,
+    macros::impl_slice_eq,
+};
+
+/// Creates a [`VecDeque`] containing the arguments.
+///
+/// Like `vec!`, `deque!` accepts either a list of elements
+/// or an element and a number of repetitions, the element being cloned.
+///
+/// # Examples
+///
+/// ```
+/// use collections::deque;
+///
+/// let deque = deque![1, 2, 3];
+/// assert_eq!(deque, [1, 2, 3]);
+///
+/// let deque = deque![0; 4];
+/// assert_eq!(deque, [0, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! deque {
+    () => {
+        $crate::vec_deque::VecDeque::new()
+    };
+    ($element:expr; $n:expr) => {{
+        let element = $element;
+        let n = $n;
+        let mut deque = $crate::vec_deque::VecDeque::with_capacity(n);
+        for _ in 0..n {
+            deque.push_back(::core::clone::Clone::clone(&element));
+        }
+        deque
+    }};
+    ($($x:expr),+ $(,)?) => {
+        $crate::vec_deque::VecDeque::from([$($x),+])
+    };
+}
+
+/// Capacity of the first buffer allocated by a growing `VecDeque`,
+/// see the growth policy in the [`VecDeque`] docs.
+const MIN_CAPACITY: usize = 4;
+
+/// Factor by which a growing `VecDeque` multiplies its capacity,
+/// see the growth policy in the [`VecDeque`] docs.
+const GROWTH_FACTOR: usize = 2;
+
+/// A double-ended queue implemented with a growable ring buffer.
+///
+/// The `VecDeque` allows pushing and popping elements at either end in
+/// amortized constant time, and indexing in constant time. The elements
+/// are stored in a single buffer, in which they may wrap around from the
+/// end to the start: [`as_slices`](VecDeque::as_slices) returns both
+/// parts, and [`make_contiguous`](VecDeque::make_contiguous) moves them
+/// into a single slice.
+///
+/// Unlike a [`LinkedList`], it doesn't allocate for each element, which
+/// makes it faster for most queue workloads.
+///
+/// The buffer is allocated with `A`, the global allocator by default.
+/// Other allocators can be used with the `allocator_api` feature.
+///
+/// # Capacity
+///
+/// When it needs more room, the buffer grows to twice its capacity,
+/// and to at least 4 elements, so that pushing elements one by one takes
+/// amortized constant time. [`reserve`](VecDeque::reserve) follows this
+/// policy, while [`reserve_exact`](VecDeque::reserve_exact) allocates
+/// only what is requested. The buffer never shrinks by itself, see
+/// [`shrink_to`](VecDeque::shrink_to) and
+/// [`shrink_to_fit`](VecDeque::shrink_to_fit).
+///
+/// Capacities are overflow-checked: requesting more than `isize::MAX`
+/// bytes fails with [`TryReserveError::CapacityOverflow`].
+pub struct VecDeque<T, A: Allocator = Global> {
+    ptr: NonNull<T>,
+    /// Capacity of the buffer, `usize::MAX` if `T` is zero-sized.
+    cap: usize,
+    /// Index in the buffer of the front element.
+    head: usize,
+    len: usize,
+    alloc: A,
+
+    /// Indicates that `VecDeque` owns some `T`
+    marker: PhantomData<T>,
+}
+
+/// An iterator over the elements of a `VecDeque`.
+///
+/// This `struct` is created by [`VecDeque::iter`].
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+/// A mutable iterator over the elements of a `VecDeque`.
+///
+/// This `struct` is created by [`VecDeque::iter_mut`].
+pub struct IterMut<'a, T> {
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>,
+}
+
+/// An owning iterator over the elements of a `VecDeque`.
+///
+/// This `struct` is created by the [`into_iter`] method on [`VecDeque`]
+/// (provided by the `IntoIterator` trait).
+///
+/// [`into_iter`]: VecDeque::into_iter
+pub struct IntoIter<T, A: Allocator = Global> {
+    deque: VecDeque<T, A>,
+}
+
+/// Panics or aborts on a failed reservation, like `Vec` does.
+fn handle_reserve(result: Result<(), TryReserveError>) {
+    match result {
+        Ok(()) => {}
+        Err(TryReserveError::CapacityOverflow) => panic!("capacity overflow"),
+        Err(TryReserveError::AllocError { layout }) => handle_alloc_error(layout),
+    }
+}
+
+// Private methods
+impl<T, A: Allocator> VecDeque<T, A> {
+    const IS_ZST: bool = mem::size_of::<T>() == 0;
+
+    /// Returns the buffer index `addend` slots after `index`.
+    #[inline]
+    fn wrap_add(&self, index: usize, addend: usize) -> usize {
+        let i = index.wrapping_add(addend);
+        // `i < index` if the addition overflowed, with a zero-sized `T`.
+        if i >= self.cap || i < index {
+            i.wrapping_sub(self.cap)
+        } else {
+            i
+        }
+    }
+
+    /// Returns the buffer index `subtrahend` slots before `index`.
+    #[inline]
+    fn wrap_sub(&self, index: usize, subtrahend: usize) -> usize {
+        if index >= subtrahend {
+            index - subtrahend
+        } else {
+            index.wrapping_sub(subtrahend).wrapping_add(self.cap)
+        }
+    }
+
+    /// Returns the buffer index of the element at position `index`.
+    #[inline]
+    fn to_physical(&self, index: usize) -> usize {
+        self.wrap_add(self.head, index)
+    }
+
+    /// Returns a pointer to the slot at buffer index `index`.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be <= `cap`.
+    #[inline]
+    unsafe fn slot(&self, index: usize) -> *mut T {
+        self.ptr.as_ptr().add(index)
+    }
+
+    /// Returns the buffer ranges holding the front and the back part of
+    /// the elements, the back one being empty if they don't wrap around.
+    #[inline]
+    fn slice_ranges(&self) -> (Range<usize>, Range<usize>) {
+        self.physical_ranges(0..self.len)
+    }
+
+    /// Returns the buffer ranges holding the front and the back part of
+    /// the positions in `range`, the back one being empty if they don't
+    /// wrap around.
+    fn physical_ranges(&self, range: Range<usize>) -> (Range<usize>, Range<usize>) {
+        let start = self.to_physical(range.start);
+        let len = range.end - range.start;
+        let head_len = self.cap - start;
+        if len <= head_len {
+            (start..start + len, 0..0)
+        } else {
+            (start..self.cap, 0..len - head_len)
+        }
+    }
+
+    /// Moves the elements at the positions in `range` one slot towards
+    /// the back, the slot at `range.start` being left uninitialized.
+    ///
+    /// # Safety
+    ///
+    /// `range.end` must be < `cap`, and the slot at `range.end` must be
+    /// unused.
+    unsafe fn shift_back(&mut self, range: Range<usize>) {
+        let (front, back) = self.physical_ranges(range);
+        ptr::copy(self.slot(0), self.slot(1), back.len());
+        if front.end == self.cap {
+            // The last element of the front part wraps around.
+            ptr::copy_nonoverlapping(self.slot(self.cap - 1), self.slot(0), 1);
+            ptr::copy(self.slot(front.start), self.slot(front.start + 1), front.len() - 1);
+        } else {
+            ptr::copy(self.slot(front.start), self.slot(front.start + 1), front.len());
+        }
+    }
+
+    /// Moves the elements at the positions in `range` one slot towards
+    /// the front, the slot at `range.end - 1` being left uninitialized.
+    ///
+    /// # Safety
+    ///
+    /// `range.start` must be > 0, and the slot at `range.start - 1` must
+    /// be unused.
+    unsafe fn shift_front(&mut self, range: Range<usize>) {
+        let (front, back) = self.physical_ranges(range);
+        for part in [front, back] {
+            if part.is_empty() {
+                continue;
+            }
+            if part.start == 0 {
+                // The first element of the part wraps around.
+                ptr::copy_nonoverlapping(self.slot(0), self.slot(self.cap - 1), 1);
+                ptr::copy(self.slot(1), self.slot(0), part.len() - 1);
+            } else {
+                ptr::copy(self.slot(part.start), self.slot(part.start - 1), part.len());
+            }
+        }
+    }
+
+    /// Moves the elements to a new buffer of `cap` slots, at its start.
+    ///
+    /// `T` must not be zero-sized, and `cap` must be >= `len`.
+    fn set_capacity(&mut self, cap: usize) -> Result<(), TryReserveError> {
+        debug_assert!(!Self::IS_ZST && cap >= self.len);
+
+        let ptr = if cap == 0 {
+            NonNull::dangling()
+        } else {
+            let layout = Layout::array::<T>(cap).map_err(|_| TryReserveError::CapacityOverflow)?;
+            self.alloc
+                .allocate(layout)
+                .map_err(|_| TryReserveError::AllocError { layout })?
+                .cast::<T>()
+        };
+
+        let (front, back) = self.slice_ranges();
+        unsafe {
+            ptr::copy_nonoverlapping(self.slot(front.start), ptr.as_ptr(), front.len());
+            ptr::copy_nonoverlapping(
+                self.slot(back.start),
+                ptr.as_ptr().add(front.len()),
+                back.len(),
+            );
+            self.free_buffer();
+        }
+
+        self.ptr = ptr;
+        self.cap = cap;
+        self.head = 0;
+        Ok(())
+    }
+
+    /// Deallocates the buffer, without dropping the elements.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must not be used anymore.
+    unsafe fn free_buffer(&mut self) {
+        if !Self::IS_ZST && self.cap != 0 {
+            let layout = Layout::array::<T>(self.cap).unwrap();
+            self.alloc.deallocate(self.ptr.cast(), layout);
+        }
+    }
+
+    /// Drops the elements and deallocates the buffer.
+    ///
+    /// If dropping an element panics, the buffer is leaked.
+    fn drop_buffer(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+            self.free_buffer();
+        }
+    }
+
+    /// Makes room for one more element.
+    #[inline]
+    fn grow_if_full(&mut self) {
+        if self.len == self.cap {
+            self.reserve(1);
+        }
+    }
+}
+
+impl<T> VecDeque<T> {
+    /// Creates an empty `VecDeque`.
+    ///
+    /// It doesn't allocate until elements are pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque: VecDeque<u32> = VecDeque::new();
+    /// assert_eq!(deque.capacity(), 0);
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates an empty `VecDeque` with room for at least `capacity`
+    /// elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque: VecDeque<u32> = VecDeque::with_capacity(10);
+    /// assert!(deque.capacity() >= 10);
+    /// ```
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> VecDeque<T, A> {
+    /// Creates an empty `VecDeque` allocating its buffer with `alloc`.
+    ///
+    /// Allocators other than the global one can only be used with the
+    /// `allocator_api` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque: VecDeque<u32> = VecDeque::new();
+    /// let mut other = VecDeque::new_in(deque.allocator().clone());
+    /// other.push_back(1);
+    /// assert_eq!(other, [1]);
+    /// ```
+    #[inline]
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            cap: if Self::IS_ZST { usize::MAX } else { 0 },
+            head: 0,
+            len: 0,
+            alloc,
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates an empty `VecDeque` with room for at least `capacity`
+    /// elements, allocating its buffer with `alloc`.
+    ///
+    /// See [`with_capacity`](VecDeque::with_capacity).
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        let mut deque = Self::new_in(alloc);
+        if !Self::IS_ZST && capacity != 0 {
+            handle_reserve(deque.set_capacity(capacity));
+        }
+        deque
+    }
+
+    /// Returns a reference to the underlying allocator.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Returns the number of elements the `VecDeque` can hold without
+    /// reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// deque.push_back(1);
+    /// assert!(deque.capacity() >= 1);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Returns the length of the `VecDeque`.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_front(2);
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the `VecDeque` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// assert!(deque.is_empty());
+    ///
+    /// deque.push_back(1);
+    /// assert!(!deque.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Reserves capacity for at least `additional` more elements,
+    /// following the [growth policy](VecDeque#capacity).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1].into();
+    /// deque.reserve(10);
+    /// assert!(deque.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve(additional));
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements,
+    /// following the [growth policy](VecDeque#capacity), returning an
+    /// error instead of panicking or aborting.
+    ///
+    /// On error, the `VecDeque` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::{errors::TryReserveError, vec_deque::VecDeque};
+    ///
+    /// let mut deque: VecDeque<u32> = VecDeque::new();
+    /// deque.try_reserve(10).expect("out of memory");
+    /// assert!(deque.capacity() >= 10);
+    ///
+    /// assert_eq!(deque.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let needed = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if needed <= self.cap {
+            return Ok(());
+        }
+
+        // Not reached with a zero-sized `T`, whose capacity is `usize::MAX`.
+        let cap = needed
+            .max(self.cap.saturating_mul(GROWTH_FACTOR))
+            .max(MIN_CAPACITY);
+        self.set_capacity(cap)
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more
+    /// elements, without growing further.
+    ///
+    /// Prefer [`reserve`](VecDeque::reserve) if more elements are
+    /// expected to be pushed afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1].into();
+    /// deque.reserve_exact(10);
+    /// assert_eq!(deque.capacity(), 11);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve_exact(additional));
+    }
+
+    /// Tries to reserve the minimum capacity for exactly `additional` more
+    /// elements, returning an error instead of panicking or aborting.
+    ///
+    /// On error, the `VecDeque` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::{errors::TryReserveError, vec_deque::VecDeque};
+    ///
+    /// let mut deque: VecDeque<u32> = VecDeque::new();
+    /// deque.try_reserve_exact(10).expect("out of memory");
+    /// assert_eq!(deque.capacity(), 10);
+    ///
+    /// assert_eq!(deque.try_reserve_exact(usize::MAX), Err(TryReserveError::CapacityOverflow));
+    /// ```
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let needed = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if needed <= self.cap {
+            return Ok(());
+        }
+        self.set_capacity(needed)
+    }
+
+    /// Shrinks the capacity of the `VecDeque` as much as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = VecDeque::with_capacity(10);
+    /// deque.extend([1, 2, 3]);
+    ///
+    /// deque.shrink_to_fit();
+    /// assert_eq!(deque.capacity(), 3);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    /// Shrinks the capacity of the `VecDeque` with a lower bound.
+    ///
+    /// The capacity remains at least as large as both the length and
+    /// `min_capacity`. If the capacity is already smaller, this has no
+    /// effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = VecDeque::with_capacity(10);
+    /// deque.extend([1, 2, 3]);
+    ///
+    /// deque.shrink_to(4);
+    /// assert_eq!(deque.capacity(), 4);
+    /// deque.shrink_to(0);
+    /// assert_eq!(deque.capacity(), 3);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let cap = self.len.max(min_capacity);
+        if !Self::IS_ZST && self.cap > cap {
+            handle_reserve(self.set_capacity(cap));
+        }
+    }
+
+    /// Provides a reference to the front element,
+    /// or `None` if the `VecDeque` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// assert_eq!(deque.front(), None);
+    ///
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Provides a mutable reference to the front element,
+    /// or `None` if the `VecDeque` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1, 2].into();
+    /// if let Some(x) = deque.front_mut() {
+    ///     *x = 5;
+    /// }
+    /// assert_eq!(deque, [5, 2]);
+    /// ```
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Provides a reference to the back element,
+    /// or `None` if the `VecDeque` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// assert_eq!(deque.back(), None);
+    ///
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Provides a mutable reference to the back element,
+    /// or `None` if the `VecDeque` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1, 2].into();
+    /// if let Some(x) = deque.back_mut() {
+    ///     *x = 5;
+    /// }
+    /// assert_eq!(deque, [1, 5]);
+    /// ```
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// Provides a reference to the element at position `index`,
+    /// or `None` if there is no element at that position.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.get(1), Some(&2));
+    /// assert_eq!(deque.get(2), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            Some(unsafe { &*self.slot(self.to_physical(index)) })
+        } else {
+            None
+        }
+    }
+
+    /// Provides a mutable reference to the element at position `index`,
+    /// or `None` if there is no element at that position.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1, 2].into();
+    /// if let Some(x) = deque.get_mut(1) {
+    ///     *x = 7;
+    /// }
+    /// assert_eq!(deque, [1, 7]);
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            Some(unsafe { &mut *self.slot(self.to_physical(index)) })
+        } else {
+            None
+        }
+    }
+
+    /// Swaps the elements at positions `i` and `j`.
+    ///
+    /// `i` and `j` may be equal.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<char> = "abcd".chars().collect();
+    ///
+    /// deque.swap(1, 3);
+    /// assert_eq!(deque, ['a', 'd', 'c', 'b']);
+    /// ```
+    pub fn swap(&mut self, i: usize, j: usize) {
+        let max = i.max(j);
+        assert!(
+            max < self.len,
+            "swap index (is {}) should be < len (is {})",
+            max,
+            self.len,
+        );
+
+        unsafe { ptr::swap(self.slot(self.to_physical(i)), self.slot(self.to_physical(j))) };
+    }
+
+    /// Adds an element first in the `VecDeque`.
+    ///
+    /// This operation should compute in amortized *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// deque.push_front(2);
+    /// deque.push_front(1);
+    /// assert_eq!(deque, [1, 2]);
+    /// ```
+    pub fn push_front(&mut self, element: T) {
+        self.grow_if_full();
+        self.head = self.wrap_sub(self.head, 1);
+        unsafe { self.slot(self.head).write(element) };
+        self.len += 1;
+    }
+
+    /// Appends an element to the back of the `VecDeque`.
+    ///
+    /// This operation should compute in amortized *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(3);
+    /// assert_eq!(deque.back(), Some(&3));
+    /// ```
+    pub fn push_back(&mut self, element: T) {
+        self.grow_if_full();
+        unsafe { self.slot(self.to_physical(self.len)).write(element) };
+        self.len += 1;
+    }
+
+    /// Removes the first element and returns it,
+    /// or `None` if the `VecDeque` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1, 2].into();
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), Some(2));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let head = self.head;
+        self.head = self.wrap_add(self.head, 1);
+        self.len -= 1;
+        Some(unsafe { self.slot(head).read() })
+    }
+
+    /// Removes the last element and returns it,
+    /// or `None` if the `VecDeque` is empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1, 2].into();
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        Some(unsafe { self.slot(self.to_physical(self.len)).read() })
+    }
+
+    /// Inserts an element at position `index`, shifting the elements
+    /// on the shortest side of it.
+    ///
+    /// This operation should compute in *O*(min(`index`, `len` - `index`))
+    /// time, plus the amortized *O*(1) of a push.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` > `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1, 2, 3].into();
+    ///
+    /// deque.insert(1, 4);
+    /// assert_eq!(deque, [1, 4, 2, 3]);
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) {
+        assert!(
+            index <= self.len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            self.len,
+        );
+
+        self.grow_if_full();
+        unsafe {
+            if index <= self.len / 2 {
+                self.head = self.wrap_sub(self.head, 1);
+                self.shift_front(1..index + 1);
+            } else {
+                self.shift_back(index..self.len);
+            }
+            self.slot(self.to_physical(index)).write(element);
+        }
+        self.len += 1;
+    }
+
+    /// Inserts an element at position `index`, or returns an error if
+    /// `index` > `len`.
+    ///
+    /// This is the non-panicking counterpart of [`insert`](VecDeque::insert).
+    /// On error, `element` is dropped.
+    ///
+    /// This operation should compute in *O*(min(`index`, `len` - `index`))
+    /// time, plus the amortized *O*(1) of a push.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::{errors::InsertError, vec_deque::VecDeque};
+    ///
+    /// let mut deque = VecDeque::new();
+    ///
+    /// assert_eq!(deque.try_insert(0, 4), Ok(()));
+    /// assert_eq!(deque.front(), Some(&4));
+    ///
+    /// assert_eq!(deque.try_insert(3, 2), Err(InsertError { index: 3, len: 1 }));
+    /// assert_eq!(deque.len(), 1);
+    /// ```
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), InsertError> {
+        if index > self.len {
+            return Err(InsertError { index, len: self.len });
+        }
+        self.insert(index, element);
+        Ok(())
+    }
+
+    /// Removes the element at position `index` and returns it, shifting
+    /// the elements on the shortest side of it.
+    ///
+    /// This operation should compute in *O*(min(`index`, `len` - `index`))
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1, 2, 3].into();
+    ///
+    /// assert_eq!(deque.remove(1), 2);
+    /// assert_eq!(deque, [1, 3]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "removal index (is {}) should be < len (is {})",
+            index,
+            self.len,
+        );
+
+        unsafe {
+            let element = self.slot(self.to_physical(index)).read();
+            if index < self.len / 2 {
+                self.shift_back(0..index);
+                self.head = self.wrap_add(self.head, 1);
+            } else {
+                self.shift_front(index + 1..self.len);
+            }
+            self.len -= 1;
+            element
+        }
+    }
+
+    /// Shortens the `VecDeque`, keeping the first `len` elements and
+    /// dropping the rest.
+    ///
+    /// If `len` is greater or equal to the current length, this has no
+    /// effect. The capacity is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = (1..6).collect();
+    ///
+    /// deque.truncate(2);
+    /// assert_eq!(deque, [1, 2]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            drop(self.pop_back());
+        }
+    }
+
+    /// Removes all elements from the `VecDeque`, keeping its capacity.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1, 2].into();
+    ///
+    /// deque.clear();
+    /// assert!(deque.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+        self.head = 0;
+    }
+
+    /// Returns `true` if the `VecDeque` contains an element equal to the
+    /// given value.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque: VecDeque<u32> = [1, 2].into();
+    /// assert!(deque.contains(&2));
+    /// assert!(!deque.contains(&3));
+    /// ```
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (front, back) = self.as_slices();
+        front.contains(x) || back.contains(x)
+    }
+
+    /// Returns the elements as a pair of slices, the first one holding
+    /// the front elements and the second one the rest.
+    ///
+    /// The second slice is empty if the elements are contiguous in the
+    /// buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::with_capacity(4);
+    /// deque.push_back(2);
+    /// deque.push_back(3);
+    /// deque.push_front(1);
+    ///
+    /// let (front, back) = deque.as_slices();
+    /// assert_eq!([front, back].concat(), [1, 2, 3]);
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.slice_ranges();
+        unsafe {
+            (
+                slice::from_raw_parts(self.slot(front.start), front.len()),
+                slice::from_raw_parts(self.slot(back.start), back.len()),
+            )
+        }
+    }
+
+    /// Returns the elements as a pair of mutable slices, the first one
+    /// holding the front elements and the second one the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1, 2, 3].into();
+    ///
+    /// let (front, back) = deque.as_mut_slices();
+    /// front.iter_mut().chain(back).for_each(|x| *x *= 10);
+    /// assert_eq!(deque, [10, 20, 30]);
+    /// ```
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.slice_ranges();
+        unsafe {
+            (
+                slice::from_raw_parts_mut(self.slot(front.start), front.len()),
+                slice::from_raw_parts_mut(self.slot(back.start), back.len()),
+            )
+        }
+    }
+
+    /// Moves the elements so that they are contiguous in the buffer,
+    /// and returns them as a single mutable slice.
+    ///
+    /// Nothing is allocated. This operation should compute in *O*(*n*)
+    /// time, or *O*(1) if the elements are already contiguous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::with_capacity(4);
+    /// deque.push_back(2);
+    /// deque.push_back(3);
+    /// deque.push_front(1);
+    ///
+    /// deque.make_contiguous().sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(deque.as_slices(), (&[3, 2, 1][..], &[][..]));
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        let (front, back) = self.slice_ranges();
+        if !back.is_empty() {
+            // Moves the front part right after the back one, then swaps
+            // the two parts in place.
+            unsafe {
+                ptr::copy(self.slot(front.start), self.slot(back.end), front.len());
+                slice::from_raw_parts_mut(self.slot(0), self.len).rotate_left(back.len());
+            }
+            self.head = 0;
+        }
+        self.as_mut_slices().0
+    }
+
+    /// Provides a forward iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque: VecDeque<u32> = [1, 2].into();
+    ///
+    /// let mut iter = deque.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next_back(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+
+    /// Provides a forward iterator with mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque: VecDeque<u32> = [1, 2].into();
+    ///
+    /// for x in deque.iter_mut() {
+    ///     *x += 10;
+    /// }
+    /// assert_eq!(deque, [11, 12]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        IterMut {
+            front: front.iter_mut(),
+            back: back.iter_mut(),
+        }
+    }
+
+    /// Returns a view of the deque implementing `defmt::Format`,
+    /// printing at most `max` elements.
+    ///
+    /// The `defmt::Format` implementation of `VecDeque` itself prints
+    /// at most [`DEFMT_MAX_ELEMENTS`](crate::DEFMT_MAX_ELEMENTS) elements.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque = VecDeque::from([1, 2, 3]);
+    ///
+    /// // Logs `[1, 2, ..]`
+    /// defmt::info!("{}", deque.defmt_prefix(2));
+    /// ```
+    #[cfg(feature = "defmt")]
+    #[inline]
+    pub fn defmt_prefix(&self, max: usize) -> DefmtPrefix<'_, T, A> {
+        DefmtPrefix { deque: self, max }
+    }
+}
+
+impl<T> Default for VecDeque<T> {
+    /// Creates an empty `VecDeque`.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for VecDeque<T, A> {
+    /// Returns a deep copy of the `VecDeque`, with a contiguous buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque: VecDeque<u32> = [1, 2].into();
+    ///
+    /// let mut copy = deque.clone();
+    /// copy.push_back(3);
+    /// assert_eq!(deque, [1, 2]);
+    /// assert_eq!(copy, [1, 2, 3]);
+    /// ```
+    fn clone(&self) -> Self {
+        let mut deque = Self::with_capacity_in(self.len, self.alloc.clone());
+        deque.extend(self.iter().cloned());
+        deque
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for VecDeque<T, A> {
+    /// Formats the `VecDeque` like a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque: VecDeque<u32> = (1..4).collect();
+    /// assert_eq!(format!("{:?}", deque), "[1, 2, 3]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialOrd, A: Allocator> PartialOrd for VecDeque<T, A> {
+    /// Compares the `VecDeque`s lexicographically.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<T: Ord, A: Allocator> Ord for VecDeque<T, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other)
+    }
+}
+
+impl<T: Hash, A: Allocator> Hash for VecDeque<T, A> {
+    /// Hashes the elements like a [`LinkedList`] of the same elements,
+    /// whatever the layout of the buffer.
+    ///
+    /// [`LinkedList`]: crate::linked_list::LinkedList
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for element in self {
+            element.hash(state);
+        }
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for VecDeque<T, A> {
+    type Output = T;
+
+    /// Provides a reference to the element at position `index`.
+    /// [`get`](VecDeque::get) doesn't panic if there is no element
+    /// at that position.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// deque.push_back(3);
+    /// deque.push_front(5);
+    /// assert_eq!(deque[0], 5);
+    /// assert_eq!(deque[1], 3);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(element) => element,
+            None => panic!("index out of bounds: the len is {} but the index is {}", self.len, index),
+        }
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for VecDeque<T, A> {
+    /// Provides a mutable reference to the element at position `index`.
+    /// [`get_mut`](VecDeque::get_mut) doesn't panic if there is no element
+    /// at that position.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` >= `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// deque.push_back(5);
+    /// deque[0] = 1;
+    /// assert_eq!(deque[0], 1);
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(element) => element,
+            None => panic!("index out of bounds: the len is {} but the index is {}", len, index),
+        }
+    }
+}
+
+#[cfg(not(feature = "may_dangle"))]
+impl<T, A: Allocator> Drop for VecDeque<T, A> {
+    fn drop(&mut self) {
+        self.drop_buffer();
+    }
+}
+
+// The elements are only dropped, never accessed: `marker` tells
+// dropck that they are owned.
+#[cfg(feature = "may_dangle")]
+unsafe impl<#[may_dangle] T, A: Allocator> Drop for VecDeque<T, A> {
+    fn drop(&mut self) {
+        self.drop_buffer();
+    }
+}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for VecDeque<T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for VecDeque<T, A> {}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter {
+            front: self.front.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter").field(&self.len()).finish()
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T> fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IterMut").field(&self.len()).finish()
+    }
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len, Some(self.deque.len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for IntoIter<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.deque).finish()
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for VecDeque<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    /// Consumes the `VecDeque` into an iterator yielding elements by value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque: VecDeque<u32> = [1, 2].into();
+    ///
+    /// let mut iter = deque.into_iter();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> IntoIter<T, A> {
+        IntoIter { deque: self }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a VecDeque<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut VecDeque<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for VecDeque<T> {
+    /// Creates a `VecDeque` from an iterator,
+    /// the first element being at the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque: VecDeque<u32> = (1..4).collect();
+    /// assert_eq!(deque.front(), Some(&1));
+    /// assert_eq!(deque.back(), Some(&3));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T> From<Vec<T>> for VecDeque<T> {
+    /// Creates a `VecDeque` from a `Vec`, the first element being at
+    /// the front.
+    ///
+    /// The buffer of the `Vec` is reused, nothing is moved nor allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque = VecDeque::from(vec![1, 2, 3]);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    fn from(vec: Vec<T>) -> Self {
+        let mut vec = ManuallyDrop::new(vec);
+        Self {
+            // A `Vec` pointer is never null, even when it didn't allocate.
+            ptr: unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) },
+            cap: vec.capacity(),
+            head: 0,
+            len: vec.len(),
+            alloc: Global,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VecDeque<T> {
+    /// Creates a `VecDeque` from an array,
+    /// the first element being at the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let deque = VecDeque::from([1, 2, 3]);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        IntoIterator::into_iter(array).collect()
+    }
+}
+
+impl<T, A: Allocator> From<VecDeque<T, A>> for Vec<T> {
+    /// Converts a `VecDeque` into a `Vec`, the front element being first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let vec = Vec::from(VecDeque::from([1, 2, 3]));
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    fn from(deque: VecDeque<T, A>) -> Self {
+        let mut vec = Vec::with_capacity(deque.len);
+        vec.extend(deque);
+        vec
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for VecDeque<T, A> {
+    /// Appends the elements of an iterator to the back of the `VecDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// deque.push_back(1);
+    ///
+    /// deque.extend(vec![2, 3]);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for element in iter {
+            self.push_back(element);
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy, A: Allocator> Extend<&'a T> for VecDeque<T, A> {
+    /// Appends copies of the elements of an iterator
+    /// to the back of the `VecDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use collections::vec_deque::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// deque.push_back(1);
+    ///
+    /// deque.extend(&[2, 3]);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// A view of a [`VecDeque`] implementing `defmt::Format`,
+/// printing only the first elements.
+///
+/// This `struct` is created by [`VecDeque::defmt_prefix`].
+#[cfg(feature = "defmt")]
+pub struct DefmtPrefix<'a, T, A: Allocator = Global> {
+    deque: &'a VecDeque<T, A>,
+    max: usize,
+}
+
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format, A: Allocator> defmt::Format for DefmtPrefix<'_, T, A> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        crate::format::defmt_prefix(f, self.deque.iter(), self.max);
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format, A: Allocator> defmt::Format for VecDeque<T, A> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        self.defmt_prefix(crate::DEFMT_MAX_ELEMENTS).format(f)
+    }
+}
+
+impl_slice_eq! { [A: Allocator, B: Allocator] VecDeque<T, A>, VecDeque<U, B> }
+impl_slice_eq! { [A: Allocator] VecDeque<T, A>, [U] }
+impl_slice_eq! { [A: Allocator] VecDeque<T, A>, &[U] }
+impl_slice_eq! { [A: Allocator] VecDeque<T, A>, &mut [U] }
+impl_slice_eq! { [A: Allocator, const N: usize] VecDeque<T, A>, [U; N] }
+impl_slice_eq! { [A: Allocator] VecDeque<T, A>, Vec<U> }
+impl_slice_eq! { [A: Allocator] [T], VecDeque<U, A> }
+impl_slice_eq! { [A: Allocator] &[T], VecDeque<U, A> }
+impl_slice_eq! { [A: Allocator] &mut [T], VecDeque<U, A> }
+impl_slice_eq! { [A: Allocator, const N: usize] [T; N], VecDeque<U, A> }
+impl_slice_eq! { [A: Allocator] Vec<T>, VecDeque<U, A> }
+impl_slice_eq! { [A: Allocator, B: Allocator] VecDeque<T, A>, LinkedList<U, B> }
+impl_slice_eq! { [A: Allocator, B: Allocator] LinkedList<T, A>, VecDeque<U, B> }
+impl_slice_eq! { [A: Allocator, B: Allocator] VecDeque<T, A>, DoublyLinkedList<U, B> }
+impl_slice_eq! { [A: Allocator, B: Allocator] DoublyLinkedList<T, A>, VecDeque<U, B> }
+
+impl<T: Eq, A: Allocator> Eq for VecDeque<T, A> {}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use alloc::{format, vec};
+    use super::*;
+    use crate::test_utils::DropCounter;
+
+    /// Returns a deque whose elements `0..len` wrap around the end of a
+    /// buffer of capacity `cap`, the front one being at `head`.
+    fn wrapped(cap: usize, head: usize, len: usize) -> VecDeque<u32> {
+        let mut deque = VecDeque::with_capacity(cap);
+        for _ in 0..head {
+            deque.push_back(0);
+        }
+        for _ in 0..head {
+            deque.pop_front();
+        }
+        deque.extend(0..len as u32);
+        assert_eq!(deque.capacity(), cap);
+        assert_eq!(deque.head, head);
+        deque
+    }
+
+    #[test]
+    fn empty() {
+        let mut deque: VecDeque<u32> = VecDeque::new();
+        assert!(deque.is_empty());
+        assert_eq!(deque.len(), 0);
+        assert_eq!(deque.front(), None);
+        assert_eq!(deque.back_mut(), None);
+        assert_eq!(deque.pop_back(), None);
+        assert_eq!(deque.as_slices(), (&[][..], &[][..]));
+        assert_eq!(deque.make_contiguous(), []);
+        assert_eq!(deque.iter().next(), None);
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut deque = VecDeque::new();
+        for i in 0..10 {
+            deque.push_back(i);
+            deque.push_front(-i);
+        }
+        assert_eq!(deque.len(), 20);
+        assert_eq!(deque.front(), Some(&-9));
+        assert_eq!(deque.back(), Some(&9));
+
+        for i in (0..10).rev() {
+            assert_eq!(deque.pop_front(), Some(-i));
+            assert_eq!(deque.pop_back(), Some(i));
+        }
+        assert_eq!(deque.pop_front(), None);
+    }
+
+    #[test]
+    fn wrap_around() {
+        let mut deque = wrapped(4, 3, 3);
+        assert_eq!(deque, [0, 1, 2]);
+        assert_eq!(deque.as_slices(), (&[0][..], &[1, 2][..]));
+        assert_eq!(deque[2], 2);
+        assert_eq!(deque.iter().rev().collect::<Vec<_>>(), [&2, &1, &0]);
+
+        deque.push_front(5);
+        assert_eq!(deque.capacity(), 4);
+        assert_eq!(deque, [5, 0, 1, 2]);
+
+        // Grows, making the elements contiguous.
+        deque.push_back(3);
+        assert_eq!(deque.capacity(), 8);
+        assert_eq!(deque.as_slices(), (&[5, 0, 1, 2, 3][..], &[][..]));
+    }
+
+    #[test]
+    fn make_contiguous() {
+        for head in 0..5 {
+            for len in 0..6 {
+                let mut deque = wrapped(5, head, len);
+                let expected: Vec<u32> = (0..len as u32).collect();
+                assert_eq!(deque.make_contiguous(), &expected[..]);
+                assert_eq!(deque.as_slices().1, []);
+                assert_eq!(deque, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn insert_remove() {
+        for head in 0..4 {
+            let mut deque = wrapped(8, head, 6);
+            deque.insert(1, 10);
+            deque.insert(5, 11);
+            assert_eq!(deque, [0, 10, 1, 2, 3, 11, 4, 5]);
+            assert_eq!(deque.remove(6), 4);
+            assert_eq!(deque.remove(1), 10);
+            assert_eq!(deque.remove(0), 0);
+            assert_eq!(deque, [1, 2, 3, 11, 5]);
+        }
+
+        for cap in [4, 8] {
+            for head in 0..cap {
+                for len in 0..=cap {
+                    for index in 0..=len {
+                        let mut deque = wrapped(cap, head, len);
+                        let mut vec: Vec<u32> = (0..len as u32).collect();
+                        deque.insert(index, 10);
+                        vec.insert(index, 10);
+                        assert_eq!(deque, vec);
+
+                        assert_eq!(deque.remove(index), vec.remove(index));
+                        if index < len {
+                            let mirror = len - 1 - index;
+                            assert_eq!(deque.remove(mirror), vec.remove(mirror));
+                        }
+                        assert_eq!(deque, vec);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn try_insert() {
+        let mut deque = wrapped(4, 3, 2);
+
+        assert_eq!(deque.try_insert(3, 5), Err(InsertError { index: 3, len: 2 }));
+        assert_eq!(deque.try_insert(1, 5), Ok(()));
+        assert_eq!(deque.try_insert(3, 6), Ok(()));
+        assert_eq!(deque.try_insert(5, 7), Err(InsertError { index: 5, len: 4 }));
+        assert_eq!(deque, [0, 5, 1, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 3) should be <= len (is 2)")]
+    fn insert_out_of_bounds() {
+        let mut deque: VecDeque<u32> = [1, 2].into();
+        deque.insert(3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn index_out_of_bounds() {
+        let deque: VecDeque<u32> = [1, 2].into();
+        let _ = deque[2];
+    }
+
+    #[test]
+    fn capacity() {
+        let mut deque = wrapped(4, 2, 3);
+        deque.reserve(3);
+        assert_eq!(deque.capacity(), 8);
+        assert_eq!(deque, [0, 1, 2]);
+
+        deque.reserve_exact(6);
+        assert_eq!(deque.capacity(), 9);
+        deque.reserve_exact(5);
+        assert_eq!(deque.capacity(), 9);
+        deque.shrink_to(5);
+        assert_eq!(deque.capacity(), 5);
+        deque.shrink_to(6);
+        assert_eq!(deque.capacity(), 5);
+        assert_eq!(deque, [0, 1, 2]);
+
+        deque.shrink_to_fit();
+        assert_eq!(deque.capacity(), 3);
+        deque.clear();
+        deque.shrink_to_fit();
+        assert_eq!(deque.capacity(), 0);
+
+        assert_eq!(deque.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+        assert_eq!(
+            deque.try_reserve(isize::MAX as usize),
+            Err(TryReserveError::CapacityOverflow),
+        );
+        assert_eq!(
+            deque.try_reserve_exact(isize::MAX as usize),
+            Err(TryReserveError::CapacityOverflow),
+        );
+        assert_eq!(deque.capacity(), 0);
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut deque = VecDeque::new();
+        assert_eq!(deque.capacity(), usize::MAX);
+        for _ in 0..10 {
+            deque.push_front(());
+            deque.push_back(());
+        }
+        assert_eq!(deque.len(), 20);
+        assert_eq!(deque.iter().count(), 20);
+        assert_eq!(deque.make_contiguous().len(), 20);
+        deque.insert(3, ());
+        deque.remove(19);
+        deque.truncate(5);
+        assert_eq!(deque.into_iter().rev().count(), 5);
+    }
+
+    #[test]
+    fn drop() {
+        let drops = Cell::new(0);
+        let mut deque = VecDeque::with_capacity(4);
+        deque.push_back(DropCounter(&drops));
+        deque.push_back(DropCounter(&drops));
+        deque.push_front(DropCounter(&drops));
+        mem::drop(deque.pop_back());
+        assert_eq!(drops.get(), 1);
+        mem::drop(deque);
+        assert_eq!(drops.get(), 3);
+
+        drops.set(0);
+        let mut iter = vec![DropCounter(&drops), DropCounter(&drops)].into_iter().collect::<VecDeque<_>>().into_iter();
+        mem::drop(iter.next());
+        mem::drop(iter);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn conversions() {
+        let mut vec = Vec::with_capacity(8);
+        vec.extend([1, 2, 3]);
+        let ptr = vec.as_ptr();
+
+        let mut deque = VecDeque::from(vec);
+        assert_eq!(deque.capacity(), 8);
+        assert_eq!(deque.as_slices().0.as_ptr(), ptr);
+        deque.push_front(0);
+        assert_eq!(Vec::from(deque), [0, 1, 2, 3]);
+
+        assert_eq!(VecDeque::from(Vec::<u32>::new()).capacity(), 0);
+        assert_eq!(VecDeque::from([(); 3]).len(), 3);
+    }
+
+    #[test]
+    fn traits() {
+        let deque = wrapped(4, 3, 3);
+        let other: VecDeque<u32> = (0..3).collect();
+        assert_eq!(deque, other);
+        assert_eq!(deque.clone(), [0, 1, 2]);
+        let greater = VecDeque::from([0, 2]);
+        assert!(deque < greater);
+        assert_eq!(format!("{:?}", deque), "[0, 1, 2]");
+        assert_eq!(format!("{:?}", deque.iter()), "Iter(3)");
+        assert_eq!(vec![0, 1, 2], deque);
+
+        let list: LinkedList<u32> = (0..3).collect();
+        assert!(deque == list);
+        assert!(list == deque);
+        let list: DoublyLinkedList<u32> = (0..3).collect();
+        assert!(deque == list);
+        assert!(list == deque);
+        let shorter = DoublyLinkedList::from([0, 1]);
+        assert!(deque != shorter);
+    }
+
+    #[test]
+    fn macro_literal() {
+        let deque: VecDeque<u32> = deque![];
+        assert_eq!(deque.len(), 0);
+
+        let deque = deque![4, 5, 6,];
+        assert_eq!(deque, [4, 5, 6]);
+
+        let deque = deque![7; 3];
+        assert_eq!(deque, [7, 7, 7]);
+        assert_eq!(deque.capacity(), 3);
+    }
+}